@@ -0,0 +1,137 @@
+//! Angles in degrees, minutes and seconds of arc.
+//!
+//! ```
+//! use indexing_fmt::angle::*;
+//!
+//! assert_eq!(format!("{}", Angle::from_degrees(45.208333)), "45°12′30″");
+//! assert_eq!(format!("{}", Angle::dms(-3, 7, 5)), "-3°07′05″");
+//! ```
+
+use core::fmt::Display;
+
+/// Displays an angle like `45°12′30″`.
+///
+/// The minutes and seconds always have two digits. Seconds are rounded to the given precision,
+/// carrying over into minutes and degrees where necessary.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Angle {
+    negative: bool,
+    seconds: f64,
+    precision: u32,
+}
+
+impl Angle {
+    /// The maximum number of decimal places of the seconds.
+    pub const MAX_PRECISION: u32 = 9;
+
+    /// The angle in decimal degrees.
+    pub fn from_degrees(degrees: f64) -> Self {
+        Angle {
+            negative: degrees.is_sign_negative(),
+            seconds: degrees.abs() * 3600.0,
+            precision: 0,
+        }
+    }
+
+    /// The angle from its components, where the sign of the degrees applies to the whole angle.
+    ///
+    /// Angles between `-1°` and `0°` have zero degrees and are made negative with
+    /// [negative](Angle::negative).
+    pub fn dms(degrees: i32, minutes: u32, seconds: u32) -> Self {
+        let seconds =
+            degrees.unsigned_abs() as f64 * 3600.0 + minutes as f64 * 60.0 + seconds as f64;
+        Angle {
+            negative: degrees < 0,
+            seconds,
+            precision: 0,
+        }
+    }
+
+    /// Makes the angle negative.
+    ///
+    /// ```
+    /// # use indexing_fmt::angle::*;
+    /// assert_eq!(format!("{}", Angle::dms(0, 30, 0).negative()), "-0°30′00″");
+    /// ```
+    pub fn negative(self) -> Self {
+        Angle {
+            negative: true,
+            ..self
+        }
+    }
+
+    /// Writes the seconds with the given number of decimal places, at most [Angle::MAX_PRECISION].
+    ///
+    /// ```
+    /// # use indexing_fmt::angle::*;
+    /// assert_eq!(format!("{}", Angle::from_degrees(0.5001).precision(2)), "0°30′00.36″");
+    /// ```
+    pub fn precision(self, precision: u32) -> Self {
+        Angle {
+            precision: precision.min(Self::MAX_PRECISION),
+            ..self
+        }
+    }
+}
+
+impl Display for Angle {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if !self.seconds.is_finite() {
+            let sign = if self.negative { "-" } else { "" };
+            return write!(f, "{}{}\u{b0}", sign, self.seconds);
+        }
+        let scale = 10u128.pow(self.precision);
+        let total = (self.seconds * scale as f64 + 0.5) as u128;
+        let (degrees, rest) = (total / (3600 * scale), total % (3600 * scale));
+        let (minutes, seconds) = (rest / (60 * scale), rest % (60 * scale));
+        if self.negative && total > 0 {
+            f.write_str("-")?;
+        }
+        let (whole_seconds, fraction) = (seconds / scale, seconds % scale);
+        write!(
+            f,
+            "{}\u{b0}{:02}\u{2032}{:02}",
+            degrees, minutes, whole_seconds
+        )?;
+        if self.precision > 0 {
+            let width = self.precision as usize;
+            write!(f, ".{:0width$}", fraction, width = width)?;
+        }
+        f.write_str("\u{2033}")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    extern crate std;
+
+    #[test]
+    fn rounding_carries() {
+        let res = Angle::from_degrees(9.99999);
+        assert_eq!(std::format!("{}", res), "10°00′00″");
+        assert_eq!(std::format!("{}", Angle::from_degrees(-0.0001)), "0°00′00″");
+        let res = Angle::from_degrees(1.0 / 3.0).precision(12);
+        assert_eq!(std::format!("{}", res), "0°20′00.000000000″");
+        assert_eq!(std::format!("{}", Angle::dms(180, 75, 0)), "181°15′00″");
+        assert_eq!(std::format!("{}", Angle::from_degrees(f64::NAN)), "NaN°");
+        let res = Angle::from_degrees(f64::NEG_INFINITY);
+        assert_eq!(std::format!("{}", res), "-inf°");
+    }
+
+    #[test]
+    fn negative_below_one_degree() {
+        assert_eq!(
+            std::format!("{}", Angle::dms(0, 30, 0).negative()),
+            "-0°30′00″"
+        );
+        assert_eq!(
+            std::format!("{}", Angle::dms(-2, 0, 1).negative()),
+            "-2°00′01″"
+        );
+        assert_eq!(
+            std::format!("{}", Angle::dms(0, 0, 0).negative()),
+            "0°00′00″"
+        );
+    }
+}
@@ -0,0 +1,78 @@
+//! Support for 256-bit integers of the [ethnum] and [primitive_types] crates.
+//!
+//! The decimal output of these types is converted digit by digit.
+
+use core::fmt::{Display, Write};
+
+use crate::chars::{Fallback, Mapped, subscript_char, superscript_char};
+use crate::{FormatSubscript, FormatSuperscript, Subscript, Superscript};
+
+macro_rules! impl_bignum(
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Display for Superscript<$ty> {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    let mut digits = Mapped {
+                        inner: f,
+                        map: superscript_char,
+                        fallback: Fallback::Error,
+                    };
+                    write!(digits, "{}", self.0)
+                }
+            }
+
+            impl Display for Subscript<$ty> {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    let mut digits = Mapped {
+                        inner: f,
+                        map: subscript_char,
+                        fallback: Fallback::Error,
+                    };
+                    write!(digits, "{}", self.0)
+                }
+            }
+
+            impl FormatSuperscript for $ty {
+                fn to_superscript(&self) -> Superscript<$ty> {
+                    Superscript(*self)
+                }
+            }
+
+            impl FormatSubscript for $ty {
+                fn to_subscript(&self) -> Subscript<$ty> {
+                    Subscript(*self)
+                }
+            }
+        )*
+    };
+);
+
+#[cfg(feature = "ethnum")]
+impl_bignum!(ethnum::U256, ethnum::I256);
+
+#[cfg(feature = "primitive-types")]
+impl_bignum!(primitive_types::U256, primitive_types::U512);
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    extern crate std;
+
+    #[cfg(feature = "ethnum")]
+    #[test]
+    fn ethnum_extremes() {
+        let res = std::format!("{}", ethnum::I256::MIN.to_superscript());
+        assert!(res.starts_with("⁻⁵⁷⁸⁹⁶⁰⁴⁴⁶"));
+        assert_eq!(res.chars().count(), 78);
+        let res = std::format!("{}", ethnum::U256::ZERO.to_subscript());
+        assert_eq!(res, "₀");
+    }
+
+    #[cfg(feature = "primitive-types")]
+    #[test]
+    fn primitive_types_max() {
+        let res = std::format!("{}", primitive_types::U256::MAX.to_subscript());
+        assert!(res.starts_with("₁₁₅₇₉₂₀₈₉"));
+        assert!(res.ends_with("₆₃₉₉₃₅"));
+    }
+}
@@ -0,0 +1,255 @@
+//! Formatting into fixed-size buffers without allocation.
+//!
+//! ```
+//! use indexing_fmt::*;
+//!
+//! let mut buf = [0; 16];
+//! assert_eq!((-12).to_superscript().encode_to(&mut buf), Ok("⁻¹²"));
+//!
+//! let label = buf::ScriptBuf::from(840u16.to_subscript());
+//! assert_eq!(&*label, "₈₄₀");
+//! ```
+
+use core::fmt::{Display, Write};
+
+use crate::{Subscript, Superscript};
+
+/// The buffer cannot hold the formatted text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BufferTooSmall {
+    /// The number of bytes the text requires.
+    pub required: usize,
+}
+
+impl Display for BufferTooSmall {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "buffer too small, {} bytes are required", self.required)
+    }
+}
+
+impl core::error::Error for BufferTooSmall {}
+
+/// Writes into a byte slice and counts the bytes which do not fit.
+struct Slice<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+    required: usize,
+}
+
+impl Write for Slice<'_> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let fits = self.len == self.required;
+        self.required += s.len();
+        match self.buf.get_mut(self.len..self.required) {
+            Some(dest) if fits => {
+                dest.copy_from_slice(s.as_bytes());
+                self.len = self.required;
+            }
+            _ => (),
+        }
+        Ok(())
+    }
+}
+
+macro_rules! impl_encode(
+    ($ty:ident) => {
+        impl<T> $ty<T>
+        where
+            Self: Display,
+        {
+            /// Formats the script into the buffer and returns the written text.
+            ///
+            /// Every script digit takes up to three bytes in UTF-8.
+            ///
+            /// ```
+            /// # use indexing_fmt::*;
+            /// let mut buf = [0; 4];
+            /// let error = 123.to_subscript().encode_to(&mut buf);
+            /// assert_eq!(error, Err(buf::BufferTooSmall { required: 9 }));
+            /// ```
+            pub fn encode_to<'a>(&self, buf: &'a mut [u8]) -> Result<&'a str, BufferTooSmall> {
+                let mut slice = Slice {
+                    buf,
+                    len: 0,
+                    required: 0,
+                };
+                // The writer itself never fails, so errors can only stem from invalid options
+                // which leave the text incomplete
+                let complete = write!(slice, "{}", self).is_ok();
+                let (len, required) = (slice.len, slice.required);
+                if len < required || !complete {
+                    return Err(BufferTooSmall { required });
+                }
+                Ok(core::str::from_utf8(&buf[..len]).unwrap_or_default())
+            }
+        }
+    };
+);
+
+impl_encode!(Superscript);
+impl_encode!(Subscript);
+
+/// A string of at most `N` bytes which is stored inline.
+///
+/// Writing more than `N` bytes fails with [core::fmt::Error] and keeps everything written before
+/// the string which did not fit. The [From] impls for integer scripts pick a capacity which fits
+/// every value of the type.
+///
+/// ```
+/// # use indexing_fmt::*;
+/// use core::fmt::Write;
+/// use indexing_fmt::buf::ScriptBuf;
+///
+/// let mut name = ScriptBuf::<16>::new();
+/// write!(name, "x{}", 2.to_superscript()).unwrap();
+/// assert_eq!(name.as_str(), "x²");
+/// assert!(write!(name, "{}", u64::MAX.to_superscript()).is_err());
+/// ```
+#[derive(Clone, Copy)]
+pub struct ScriptBuf<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> ScriptBuf<N> {
+    /// Creates an empty buffer.
+    pub const fn new() -> Self {
+        ScriptBuf {
+            bytes: [0; N],
+            len: 0,
+        }
+    }
+
+    /// The text written so far.
+    pub fn as_str(&self) -> &str {
+        // Only complete str slices are copied into the buffer
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+
+    /// The maximum number of bytes the buffer holds, which is `N`.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+}
+
+impl<const N: usize> Default for ScriptBuf<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Write for ScriptBuf<N> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let end = self.len + s.len();
+        self.bytes
+            .get_mut(self.len..end)
+            .ok_or(core::fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+impl<const N: usize> core::ops::Deref for ScriptBuf<N> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> Display for ScriptBuf<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+impl<const N: usize> core::fmt::Debug for ScriptBuf<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize, const M: usize> PartialEq<ScriptBuf<M>> for ScriptBuf<N> {
+    fn eq(&self, other: &ScriptBuf<M>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize> Eq for ScriptBuf<N> {}
+
+impl<const N: usize> PartialEq<str> for ScriptBuf<N> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for ScriptBuf<N> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const N: usize> core::hash::Hash for ScriptBuf<N> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+macro_rules! impl_from_script(
+    ($($ty:ty => $capacity:literal),* $(,)?) => {
+        $(
+            impl From<Superscript<$ty>> for ScriptBuf<$capacity> {
+                fn from(value: Superscript<$ty>) -> Self {
+                    let mut buf = ScriptBuf::new();
+                    // The capacity fits three bytes for the sign and each digit of the type
+                    let _ = write!(buf, "{}", value);
+                    buf
+                }
+            }
+
+            impl From<Subscript<$ty>> for ScriptBuf<$capacity> {
+                fn from(value: Subscript<$ty>) -> Self {
+                    let mut buf = ScriptBuf::new();
+                    let _ = write!(buf, "{}", value);
+                    buf
+                }
+            }
+        )*
+    };
+);
+
+impl_from_script!(
+    u8 => 9, i8 => 12, u16 => 15, i16 => 18, u32 => 30, i32 => 33, u64 => 60, i64 => 60,
+    usize => 60, isize => 60, u128 => 117, i128 => 120,
+);
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn exact_fit() {
+        let mut buf = [0; 10];
+        assert_eq!(i8::MIN.to_superscript().encode_to(&mut buf), Ok("⁻¹²⁸"));
+        let mut buf = [0; 9];
+        let error = buf::BufferTooSmall { required: 10 };
+        assert_eq!(i8::MIN.to_superscript().encode_to(&mut buf), Err(error));
+    }
+
+    #[test]
+    fn script_buf_fits_extremes() {
+        assert_eq!(buf::ScriptBuf::from(i128::MIN.to_subscript()).len(), 120);
+        assert_eq!(
+            buf::ScriptBuf::from(u128::MAX.to_superscript())
+                .chars()
+                .count(),
+            39
+        );
+        assert_eq!(buf::ScriptBuf::from(i64::MIN.to_subscript()).len(), 60);
+        assert_eq!(
+            buf::ScriptBuf::from(isize::MIN.to_superscript()),
+            "⁻⁹²²³³⁷²⁰³⁶⁸⁵⁴⁷⁷⁵⁸⁰⁸"
+        );
+    }
+}
@@ -0,0 +1,333 @@
+use core::fmt::Write;
+
+use crate::{
+    SUBSCRIPT_DIGITS, SUPERSCRIPT_DIGITS, ScriptPosition, from_subscript_char,
+    from_superscript_char,
+};
+
+/// Determines what happens to characters without a superscript or subscript form.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Fallback {
+    /// Writes the character unchanged.
+    #[default]
+    Keep,
+    /// Omits the character.
+    Skip,
+    /// Writes the given character instead.
+    Replace(char),
+    /// Aborts formatting with [core::fmt::Error].
+    Error,
+}
+
+impl Fallback {
+    pub(crate) fn write(&self, f: &mut impl Write, c: char) -> core::fmt::Result {
+        match self {
+            Fallback::Keep => f.write_char(c),
+            Fallback::Skip => Ok(()),
+            Fallback::Replace(r) => f.write_char(*r),
+            Fallback::Error => Err(core::fmt::Error),
+        }
+    }
+}
+
+/// Space between a base and its script, or between the factors of a product.
+///
+/// ```
+/// use indexing_fmt::*;
+///
+/// assert_eq!(format!("x{}{}", Spacing::Hair, 2.to_subscript()), "x\u{200a}₂");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Spacing {
+    /// No space at all.
+    #[default]
+    None,
+    /// U+200A HAIR SPACE
+    Hair,
+    /// U+2009 THIN SPACE
+    Thin,
+    /// Any other character.
+    Custom(char),
+}
+
+impl core::fmt::Display for Spacing {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Spacing::None => Ok(()),
+            Spacing::Hair => f.write_char('\u{200a}'),
+            Spacing::Thin => f.write_char('\u{2009}'),
+            Spacing::Custom(c) => f.write_char(*c),
+        }
+    }
+}
+
+/// Unicode versions which added superscript or subscript characters.
+///
+/// Selecting an older version avoids characters which fonts and platforms of that time may not
+/// support. Newer versions enable additional letters such as the superscript `ꟲ`, `ꟳ` and `ꟴ` of
+/// Unicode 14.0.
+///
+/// ```
+/// use indexing_fmt::UnicodeVersion;
+/// use indexing_fmt::label::Letters;
+///
+/// assert_eq!(format!("{}", Letters::subscript(8)), "ₕ");
+/// assert_eq!(format!("{}", Letters::subscript(8).unicode_version(UnicodeVersion::V5_1)), "h");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum UnicodeVersion {
+    /// Digits, signs, parentheses and the Latin-1 and spacing modifier letters.
+    V1_1,
+    /// Superscript `ⁱ`.
+    V3_2,
+    /// Superscript capitals and most of the phonetic modifier letters.
+    V4_0,
+    /// Superscript `ᶜ ᶠ ᶻ` and subscript `ₐ ₑ ₒ ₓ`.
+    V4_1,
+    /// Superscript `ⱽ` and subscript `ⱼ`.
+    V5_1,
+    /// Subscript `ₕ ₖ ₗ ₘ ₙ ₚ ₛ ₜ`. The default, which is supported by most current fonts.
+    #[default]
+    V6_0,
+    /// Superscript `ꟲ ꟳ ꟴ` for `C F Q` and `𐞥` for `q`.
+    V14_0,
+}
+
+impl UnicodeVersion {
+    /// The version which introduced a script character returned by [superscript_char] or
+    /// [subscript_char].
+    const fn introduced(c: char) -> UnicodeVersion {
+        match c {
+            '\u{2071}' => UnicodeVersion::V3_2,
+            '\u{1d2c}'..='\u{1d6a}' => UnicodeVersion::V4_0,
+            '\u{1d9c}'..='\u{1dbf}' | '\u{2090}'..='\u{2094}' => UnicodeVersion::V4_1,
+            '\u{2c7c}' | '\u{2c7d}' => UnicodeVersion::V5_1,
+            '\u{2095}'..='\u{209c}' => UnicodeVersion::V6_0,
+            '\u{a7f2}'..='\u{a7f4}' | '\u{107a5}' => UnicodeVersion::V14_0,
+            _ => UnicodeVersion::V1_1,
+        }
+    }
+}
+
+/// Maps a character to its script form if the form exists in the given Unicode version.
+///
+/// In [plain mode](crate::set_plain_mode) every character is kept.
+pub(crate) fn script_char(
+    c: char,
+    position: ScriptPosition,
+    version: UnicodeVersion,
+) -> Option<char> {
+    if crate::plain_mode() {
+        return Some(c);
+    }
+    let s = match (position, c) {
+        (ScriptPosition::Superscript, 'C') => Some('\u{a7f2}'),
+        (ScriptPosition::Superscript, 'F') => Some('\u{a7f3}'),
+        (ScriptPosition::Superscript, 'Q') => Some('\u{a7f4}'),
+        (ScriptPosition::Superscript, 'q') => Some('\u{107a5}'),
+        (ScriptPosition::Superscript, c) => superscript_char(c),
+        (ScriptPosition::Subscript, c) => subscript_char(c),
+    };
+    s.filter(|s| UnicodeVersion::introduced(*s) <= version)
+}
+
+/// The superscript form of a digit, sign, parenthesis, Latin letter or Greek letter.
+///
+/// Superscripts of all lowercase letters but `q` exist, while `C`, `F`, `Q`, `S`, `X`, `Y` and
+/// `Z` have no uppercase superscript in Unicode 6.0. Of the Greek letters only `β γ δ θ φ χ` have
+/// one.
+///
+/// ```
+/// use indexing_fmt::*;
+///
+/// assert_eq!(superscript_char('n'), Some('ⁿ'));
+/// assert_eq!(superscript_char('('), Some('⁽'));
+/// assert_eq!(superscript_char('q'), None);
+/// assert_eq!(superscript_char('φ'), Some('ᵠ'));
+/// ```
+pub const fn superscript_char(c: char) -> Option<char> {
+    let s = match c {
+        '0'..='9' => SUPERSCRIPT_DIGITS[c as usize - '0' as usize],
+        '+' => '\u{207a}',
+        '-' | '\u{2212}' => '\u{207b}',
+        '=' => '\u{207c}',
+        '(' => '\u{207d}',
+        ')' => '\u{207e}',
+        'a' => '\u{1d43}',
+        'b' => '\u{1d47}',
+        'c' => '\u{1d9c}',
+        'd' => '\u{1d48}',
+        'e' => '\u{1d49}',
+        'f' => '\u{1da0}',
+        'g' => '\u{1d4d}',
+        'h' => '\u{02b0}',
+        'i' => '\u{2071}',
+        'j' => '\u{02b2}',
+        'k' => '\u{1d4f}',
+        'l' => '\u{02e1}',
+        'm' => '\u{1d50}',
+        'n' => '\u{207f}',
+        'o' => '\u{1d52}',
+        'p' => '\u{1d56}',
+        'r' => '\u{02b3}',
+        's' => '\u{02e2}',
+        't' => '\u{1d57}',
+        'u' => '\u{1d58}',
+        'v' => '\u{1d5b}',
+        'w' => '\u{02b7}',
+        'x' => '\u{02e3}',
+        'y' => '\u{02b8}',
+        'z' => '\u{1dbb}',
+        'A' => '\u{1d2c}',
+        'B' => '\u{1d2e}',
+        'D' => '\u{1d30}',
+        'E' => '\u{1d31}',
+        'G' => '\u{1d33}',
+        'H' => '\u{1d34}',
+        'I' => '\u{1d35}',
+        'J' => '\u{1d36}',
+        'K' => '\u{1d37}',
+        'L' => '\u{1d38}',
+        'M' => '\u{1d39}',
+        'N' => '\u{1d3a}',
+        'O' => '\u{1d3c}',
+        'P' => '\u{1d3e}',
+        'R' => '\u{1d3f}',
+        'T' => '\u{1d40}',
+        'U' => '\u{1d41}',
+        'V' => '\u{2c7d}',
+        'W' => '\u{1d42}',
+        '\u{3b2}' => '\u{1d5d}',
+        '\u{3b3}' => '\u{1d5e}',
+        '\u{3b4}' => '\u{1d5f}',
+        '\u{3b8}' => '\u{1dbf}',
+        '\u{3c6}' => '\u{1d60}',
+        '\u{3c7}' => '\u{1d61}',
+        _ => return None,
+    };
+    Some(s)
+}
+
+/// The subscript form of a digit, sign, parenthesis, Latin letter or Greek letter.
+///
+/// Only the lowercase letters `a e h i j k l m n o p r s t u v x` and the Greek `β γ ρ φ χ` have
+/// subscript forms.
+///
+/// ```
+/// use indexing_fmt::*;
+///
+/// assert_eq!(subscript_char('2'), Some('₂'));
+/// assert_eq!(subscript_char('x'), Some('ₓ'));
+/// assert_eq!(subscript_char('b'), None);
+/// assert_eq!(subscript_char('ρ'), Some('ᵨ'));
+/// ```
+pub const fn subscript_char(c: char) -> Option<char> {
+    let s = match c {
+        '0'..='9' => SUBSCRIPT_DIGITS[c as usize - '0' as usize],
+        '+' => '\u{208a}',
+        '-' | '\u{2212}' => '\u{208b}',
+        '=' => '\u{208c}',
+        '(' => '\u{208d}',
+        ')' => '\u{208e}',
+        'a' => '\u{2090}',
+        'e' => '\u{2091}',
+        'h' => '\u{2095}',
+        'i' => '\u{1d62}',
+        'j' => '\u{2c7c}',
+        'k' => '\u{2096}',
+        'l' => '\u{2097}',
+        'm' => '\u{2098}',
+        'n' => '\u{2099}',
+        'o' => '\u{2092}',
+        'p' => '\u{209a}',
+        'r' => '\u{1d63}',
+        's' => '\u{209b}',
+        't' => '\u{209c}',
+        'u' => '\u{1d64}',
+        'v' => '\u{1d65}',
+        'x' => '\u{2093}',
+        '\u{3b2}' => '\u{1d66}',
+        '\u{3b3}' => '\u{1d67}',
+        '\u{3c1}' => '\u{1d68}',
+        '\u{3c6}' => '\u{1d69}',
+        '\u{3c7}' => '\u{1d6a}',
+        _ => return None,
+    };
+    Some(s)
+}
+
+/// Finds the ASCII or Greek character of which `c` is the superscript or subscript form.
+pub(crate) fn baseline_char(c: char) -> Option<(char, ScriptPosition)> {
+    (' '..='~').chain('\u{3b1}'..='\u{3c9}').find_map(|b| {
+        if superscript_char(b) == Some(c) {
+            Some((b, ScriptPosition::Superscript))
+        } else if subscript_char(b) == Some(c) {
+            Some((b, ScriptPosition::Subscript))
+        } else {
+            None
+        }
+    })
+}
+
+/// Splits a string into its base and the trailing run of superscript or subscript digits.
+///
+/// The kind of digits is determined by the last character.
+pub(crate) fn split_trailing_digits(text: &str) -> (&str, &str, Option<ScriptPosition>) {
+    let (position, value): (_, fn(char) -> Option<u8>) = match text.chars().next_back() {
+        Some(c) if from_superscript_char(c).is_some() => {
+            (ScriptPosition::Superscript, from_superscript_char)
+        }
+        Some(c) if from_subscript_char(c).is_some() => {
+            (ScriptPosition::Subscript, from_subscript_char)
+        }
+        _ => return (text, "", None),
+    };
+    let start = text
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| value(*c).is_some())
+        .last()
+        .map_or(text.len(), |(n, _)| n);
+    (&text[..start], &text[start..], Some(position))
+}
+
+/// Writes every character of the text mapped by the given function.
+pub(crate) fn write_mapped(
+    f: &mut impl Write,
+    text: &str,
+    map: fn(char) -> Option<char>,
+    fallback: Fallback,
+) -> core::fmt::Result {
+    Mapped {
+        inner: f,
+        map,
+        fallback,
+    }
+    .write_str(text)
+}
+
+/// Maps every character written to it, e.g. to format the output of another [Display] impl as
+/// script.
+///
+/// [Display]: core::fmt::Display
+pub(crate) struct Mapped<'a, W> {
+    pub(crate) inner: &'a mut W,
+    pub(crate) map: fn(char) -> Option<char>,
+    pub(crate) fallback: Fallback,
+}
+
+impl<W: Write> Write for Mapped<'_, W> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        s.chars().try_for_each(|c| self.write_char(c))
+    }
+
+    fn write_char(&mut self, c: char) -> core::fmt::Result {
+        if crate::plain_mode() {
+            return self.inner.write_char(c);
+        }
+        match (self.map)(c) {
+            Some(s) => self.inner.write_char(s),
+            None => self.fallback.write(self.inner, c),
+        }
+    }
+}
@@ -0,0 +1,662 @@
+//! Chemical formulas and ions.
+//!
+//! ```
+//! use indexing_fmt::chem::*;
+//!
+//! assert_eq!(format!("{}", formula("CuSO4·5H2O")), "CuSO₄·5H₂O");
+//! assert_eq!(format!("{}", ion("sulfate").unwrap()), "SO₄²⁻");
+//! assert_eq!(format!("{}", Formula::new(&[Part::Element("H", 2), Part::Element("O", 1)])), "H₂O");
+//! assert_eq!(format!("{}", isotope("U", 235)), "²³⁵U");
+//! assert_eq!(format!("{}", equation("2 H2 + O2 -> 2 H2O")), "2 H₂ + O₂ → 2 H₂O");
+//! ```
+
+use core::fmt::Write;
+
+use crate::decimal::Buf;
+use crate::segment::{Segment, WriteSegments};
+use crate::{FormatSubscript, FormatSuperscript, Subscript, Superscript};
+
+/// Displays a plain formula such as `"H2O"` with subscripted counts.
+///
+/// Digits are subscripted when they follow a letter, a closing bracket or another subscripted
+/// digit. All other digits, such as the coefficient of a hydrate, stay on the baseline.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PlainFormula<'a>(pub &'a str);
+
+/// Converts a plain formula. See [PlainFormula].
+pub fn formula(text: &str) -> PlainFormula<'_> {
+    PlainFormula(text)
+}
+
+impl core::fmt::Display for PlainFormula<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut subscript = false;
+        for c in self.0.chars() {
+            subscript = match c {
+                '0'..='9' if subscript => {
+                    write!(f, "{}", Subscript(c))?;
+                    continue;
+                }
+                c => c.is_alphabetic() || matches!(c, ')' | ']' | '}'),
+            };
+            f.write_char(c)?;
+        }
+        Ok(())
+    }
+}
+
+/// Displays a plain chemical equation such as `"2 H2 + O2 -> 2 H2O"` with subscripted counts.
+///
+/// The equation is split at whitespace into operators and species:
+///
+/// - The operators `->`, `<-`, `<->` and `<=>` are written as `→`, `←`, `↔` and `⇌`, while `+` and
+///   `=` are kept.
+/// - Species are written like a [PlainFormula], such that stoichiometric coefficients stay on the
+///   baseline as in `2H₂O`.
+/// - A species ending in `+` or `-` is an ion whose charge is superscripted, as in `Na⁺`. A charge
+///   magnitude has to be introduced by `^` to distinguish it from the count, as in `"SO4^2-"` for
+///   `SO₄²⁻`.
+///
+/// ```
+/// # use indexing_fmt::chem::*;
+/// let res = format!("{}", equation("Fe^3+ + 3 OH- <=> Fe(OH)3(s)"));
+/// assert_eq!(res, "Fe³⁺ + 3 OH⁻ ⇌ Fe(OH)₃(s)");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PlainEquation<'a>(pub &'a str);
+
+/// Converts a plain chemical equation. See [PlainEquation].
+pub fn equation(text: &str) -> PlainEquation<'_> {
+    PlainEquation(text)
+}
+
+/// Splits a species into its formula and its charge like `"2-"` or `"+"`.
+fn split_charge(species: &str) -> (&str, &str) {
+    let Some(body) = species.strip_suffix(['+', '-']) else {
+        return (species, "");
+    };
+    match body.rfind('^') {
+        Some(caret) if body[caret + 1..].chars().all(|c| c.is_ascii_digit()) => {
+            (&species[..caret], &species[caret + 1..])
+        }
+        _ if body.is_empty() => (species, ""),
+        _ => (body, &species[body.len()..]),
+    }
+}
+
+impl core::fmt::Display for PlainEquation<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for piece in self.0.split_inclusive(char::is_whitespace) {
+            let token = piece.trim_end();
+            let whitespace = &piece[token.len()..];
+            match token {
+                "->" => f.write_char('\u{2192}')?,
+                "<-" => f.write_char('\u{2190}')?,
+                "<->" => f.write_char('\u{2194}')?,
+                "<=>" => f.write_char('\u{21cc}')?,
+                species => {
+                    let (formula, charge) = split_charge(species);
+                    write!(f, "{}", PlainFormula(formula))?;
+                    crate::chars::write_mapped(
+                        f,
+                        charge,
+                        crate::chars::superscript_char,
+                        crate::Fallback::Keep,
+                    )?;
+                }
+            }
+            f.write_str(whitespace)?;
+        }
+        Ok(())
+    }
+}
+
+/// A part of a [Formula].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Part<'a> {
+    /// An element with its count.
+    Element(&'a str, u32),
+    /// A group in parentheses like `(OH)₂`.
+    Group(&'a [Part<'a>], u32),
+    /// A hydrate or other addition after a dot like `·5H₂O`.
+    Hydrate(u32, &'a [Part<'a>]),
+}
+
+/// Displays a formula built from elements, parenthesized groups and hydrate parts.
+///
+/// Counts of one are omitted.
+///
+/// ```
+/// # use indexing_fmt::chem::*;
+/// use Part::*;
+///
+/// let parts = [Element("Ca", 1), Group(&[Element("O", 1), Element("H", 1)], 2)];
+/// assert_eq!(format!("{}", Formula::new(&parts)), "Ca(OH)₂");
+/// let parts = [Element("CuSO", 4), Hydrate(5, &[Element("H", 2), Element("O", 1)])];
+/// assert_eq!(format!("{}", Formula::new(&parts)), "CuSO₄·5H₂O");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Formula<'a> {
+    parts: &'a [Part<'a>],
+    charge: i32,
+}
+
+impl<'a> Formula<'a> {
+    pub fn new(parts: &'a [Part<'a>]) -> Self {
+        Formula { parts, charge: 0 }
+    }
+
+    /// Writes the [Charge] of an ion after all parts.
+    ///
+    /// ```
+    /// # use indexing_fmt::chem::*;
+    /// use Part::*;
+    ///
+    /// let sulfate = Formula::new(&[Element("S", 1), Element("O", 4)]).charge(-2);
+    /// assert_eq!(format!("{}", sulfate), "SO₄²⁻");
+    /// assert_eq!(format!("{}", Formula::new(&[Element("e", 1)]).charge(-1)), "e⁻");
+    /// ```
+    pub fn charge(self, charge: i32) -> Self {
+        Formula { charge, ..self }
+    }
+}
+
+fn write_parts(f: &mut core::fmt::Formatter<'_>, parts: &[Part<'_>]) -> core::fmt::Result {
+    let count = |f: &mut core::fmt::Formatter<'_>, count: u32| match count {
+        1 => Ok(()),
+        n => write!(f, "{}", n.to_subscript()),
+    };
+    for part in parts {
+        match part {
+            Part::Element(symbol, n) => {
+                f.write_str(symbol)?;
+                count(f, *n)?;
+            }
+            Part::Group(group, n) => {
+                f.write_char('(')?;
+                write_parts(f, group)?;
+                f.write_char(')')?;
+                count(f, *n)?;
+            }
+            Part::Hydrate(n, parts) => {
+                f.write_char('\u{b7}')?;
+                if *n > 1 {
+                    write!(f, "{}", n)?;
+                }
+                write_parts(f, parts)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+impl core::fmt::Display for Formula<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write_parts(f, self.parts)?;
+        write!(f, "{}", Charge(self.charge))
+    }
+}
+
+fn parts_segments(
+    parts: &[Part<'_>],
+    sink: &mut dyn FnMut(Segment<'_>) -> core::fmt::Result,
+) -> core::fmt::Result {
+    let count = |sink: &mut dyn FnMut(Segment<'_>) -> core::fmt::Result, n: u32| match n {
+        1 => Ok(()),
+        n => Subscript(n).write_segments(sink),
+    };
+    for part in parts {
+        match part {
+            Part::Element(symbol, n) => {
+                sink(Segment::Text(symbol))?;
+                count(sink, *n)?;
+            }
+            Part::Group(group, n) => {
+                sink(Segment::Text("("))?;
+                parts_segments(group, sink)?;
+                sink(Segment::Text(")"))?;
+                count(sink, *n)?;
+            }
+            Part::Hydrate(n, parts) => {
+                let mut buf = Buf::new();
+                write!(buf, "\u{b7}")?;
+                if *n > 1 {
+                    write!(buf, "{}", n)?;
+                }
+                sink(Segment::Text(buf.as_str()))?;
+                parts_segments(parts, sink)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+impl WriteSegments for Formula<'_> {
+    fn write_segments(
+        &self,
+        sink: &mut dyn FnMut(Segment<'_>) -> core::fmt::Result,
+    ) -> core::fmt::Result {
+        parts_segments(self.parts, sink)?;
+        Charge(self.charge).write_segments(sink)
+    }
+}
+
+/// Displays a nuclide in the notation `²³⁵U` with its mass number in front of the element symbol.
+///
+/// With [Isotope::atomic_number], the atomic number follows the mass number as subscript like
+/// `²³⁵₉₂U`. Unlike typeset notation, plain text cannot stack the two numbers on top of each other,
+/// such that they are written one after another. Depending on the font, the subscript may also be
+/// narrower or positioned differently than the superscript. Where the numbers have to be aligned,
+/// render the [Segment]s of [WriteSegments] with markup instead.
+///
+/// ```
+/// # use indexing_fmt::chem::*;
+/// assert_eq!(format!("{}", isotope("U", 235)), "²³⁵U");
+/// assert_eq!(format!("{}", isotope("U", 235).atomic_number(92)), "²³⁵₉₂U");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Isotope<'a> {
+    symbol: &'a str,
+    mass_number: u32,
+    atomic_number: Option<u32>,
+}
+
+/// Starts an [Isotope] from its element symbol and mass number.
+pub fn isotope(symbol: &str, mass_number: u32) -> Isotope<'_> {
+    Isotope {
+        symbol,
+        mass_number,
+        atomic_number: None,
+    }
+}
+
+impl Isotope<'_> {
+    /// Writes the atomic number as subscript after the mass number.
+    pub fn atomic_number(self, atomic_number: u32) -> Self {
+        Isotope {
+            atomic_number: Some(atomic_number),
+            ..self
+        }
+    }
+}
+
+impl core::fmt::Display for Isotope<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.mass_number.to_superscript())?;
+        if let Some(z) = self.atomic_number {
+            write!(f, "{}", z.to_subscript())?;
+        }
+        f.write_str(self.symbol)
+    }
+}
+
+impl WriteSegments for Isotope<'_> {
+    fn write_segments(
+        &self,
+        sink: &mut dyn FnMut(Segment<'_>) -> core::fmt::Result,
+    ) -> core::fmt::Result {
+        Superscript(self.mass_number).write_segments(sink)?;
+        if let Some(z) = self.atomic_number {
+            Subscript(z).write_segments(sink)?;
+        }
+        sink(Segment::Text(self.symbol))
+    }
+}
+
+/// Displays an electric charge as superscript, e.g. `²⁺`, with the magnitude before the sign.
+///
+/// A magnitude of one is omitted and a charge of zero is not displayed at all. See
+/// [Formula::charge] for attaching it to a formula.
+///
+/// ```
+/// # use indexing_fmt::chem::*;
+/// assert_eq!(format!("Ca{}", Charge(2)), "Ca²⁺");
+/// assert_eq!(format!("Cl{}", Charge(-1)), "Cl⁻");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Charge(pub i32);
+
+impl core::fmt::Display for Charge {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let magnitude = self.0.unsigned_abs();
+        if magnitude > 1 {
+            write!(f, "{}", magnitude.to_superscript())?;
+        }
+        match self.0 {
+            0 => Ok(()),
+            1.. => write!(f, "{}", Superscript('+')),
+            _ => write!(f, "{}", Superscript('-')),
+        }
+    }
+}
+
+/// A polyatomic ion consisting of a plain formula and its charge.
+///
+/// See [POLYATOMIC_IONS] for the built-in table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Ion {
+    pub name: &'static str,
+    pub formula: &'static str,
+    pub charge: i32,
+}
+
+impl core::fmt::Display for Ion {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}{}", PlainFormula(self.formula), Charge(self.charge))
+    }
+}
+
+macro_rules! ions(
+    ($($name:literal, $formula:literal, $charge:literal;)*) => {
+        &[$(Ion { name: $name, formula: $formula, charge: $charge },)*]
+    };
+);
+
+/// Common polyatomic ions.
+pub const POLYATOMIC_IONS: &[Ion] = ions!(
+    "ammonium", "NH4", 1;
+    "hydronium", "H3O", 1;
+    "mercury(I)", "Hg2", 2;
+    "acetate", "CH3COO", -1;
+    "arsenate", "AsO4", -3;
+    "borate", "BO3", -3;
+    "carbonate", "CO3", -2;
+    "hydrogen carbonate", "HCO3", -1;
+    "chlorate", "ClO3", -1;
+    "chlorite", "ClO2", -1;
+    "hypochlorite", "ClO", -1;
+    "perchlorate", "ClO4", -1;
+    "chromate", "CrO4", -2;
+    "dichromate", "Cr2O7", -2;
+    "cyanide", "CN", -1;
+    "hydroxide", "OH", -1;
+    "nitrate", "NO3", -1;
+    "nitrite", "NO2", -1;
+    "oxalate", "C2O4", -2;
+    "permanganate", "MnO4", -1;
+    "peroxide", "O2", -2;
+    "phosphate", "PO4", -3;
+    "hydrogen phosphate", "HPO4", -2;
+    "dihydrogen phosphate", "H2PO4", -1;
+    "silicate", "SiO3", -2;
+    "sulfate", "SO4", -2;
+    "hydrogen sulfate", "HSO4", -1;
+    "sulfite", "SO3", -2;
+    "thiocyanate", "SCN", -1;
+    "thiosulfate", "S2O3", -2;
+);
+
+/// Looks up an entry of [POLYATOMIC_IONS] by its name, ignoring ASCII case.
+pub fn ion(name: &str) -> Option<Ion> {
+    POLYATOMIC_IONS
+        .iter()
+        .find(|ion| ion.name.eq_ignore_ascii_case(name))
+        .copied()
+}
+
+/// A ligand of a [Complex] given as plain formula or abbreviation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Ligand<'a> {
+    pub formula: &'a str,
+    pub count: u32,
+}
+
+impl<'a> Ligand<'a> {
+    pub fn new(formula: &'a str, count: u32) -> Self {
+        Ligand { formula, count }
+    }
+
+    /// Ligands consisting of a single element are written without enclosing marks.
+    fn is_monatomic(&self) -> bool {
+        let mut chars = self.formula.chars();
+        chars.next().is_some_and(|c| c.is_ascii_uppercase())
+            && chars.all(|c| c.is_ascii_lowercase())
+    }
+}
+
+/// Displays a coordination complex such as `[Cu(NH₃)₄]²⁺`.
+///
+/// Polyatomic ligands and abbreviations are enclosed in parentheses, or in braces if they contain
+/// parentheses themselves. The overall charge is placed after the square brackets.
+///
+/// ```
+/// # use indexing_fmt::chem::*;
+/// let ligands = [Ligand::new("CN", 6)];
+/// let complex = Complex::new("Fe", &ligands, -3);
+/// assert_eq!(format!("{complex}"), "[Fe(CN)₆]³⁻");
+///
+/// let ligands = [Ligand::new("P(C6H5)3", 2), Ligand::new("Cl", 2)];
+/// let complex = Complex::new("Pt", &ligands, 0);
+/// assert_eq!(format!("{complex}"), "[Pt{P(C₆H₅)₃}₂Cl₂]");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Complex<'a> {
+    pub center: &'a str,
+    pub ligands: &'a [Ligand<'a>],
+    pub charge: i32,
+}
+
+impl<'a> Complex<'a> {
+    pub fn new(center: &'a str, ligands: &'a [Ligand<'a>], charge: i32) -> Self {
+        Complex {
+            center,
+            ligands,
+            charge,
+        }
+    }
+}
+
+impl core::fmt::Display for Complex<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "[{}", PlainFormula(self.center))?;
+        for ligand in self.ligands {
+            let formula = PlainFormula(ligand.formula);
+            if ligand.is_monatomic() {
+                write!(f, "{}", formula)?;
+            } else if ligand.formula.contains('(') {
+                write!(f, "{{{}}}", formula)?;
+            } else {
+                write!(f, "({})", formula)?;
+            }
+            if ligand.count > 1 {
+                write!(f, "{}", ligand.count.to_subscript())?;
+            }
+        }
+        write!(f, "]{}", Charge(self.charge))
+    }
+}
+
+impl WriteSegments for PlainFormula<'_> {
+    fn write_segments(
+        &self,
+        sink: &mut dyn FnMut(Segment<'_>) -> core::fmt::Result,
+    ) -> core::fmt::Result {
+        let segment = |text, digits| match digits {
+            true => Segment::Subscript(text),
+            false => Segment::Text(text),
+        };
+        let (mut start, mut digits, mut subscript) = (0, false, false);
+        for (n, c) in self.0.char_indices() {
+            let digit = subscript && c.is_ascii_digit();
+            if digit != digits {
+                if n > start {
+                    sink(segment(&self.0[start..n], digits))?;
+                }
+                (start, digits) = (n, digit);
+            }
+            subscript = digit || c.is_alphabetic() || matches!(c, ')' | ']' | '}');
+        }
+        match start < self.0.len() {
+            true => sink(segment(&self.0[start..], digits)),
+            false => Ok(()),
+        }
+    }
+}
+
+/// The charge is written as `2+` or `-`.
+impl WriteSegments for Charge {
+    fn write_segments(
+        &self,
+        sink: &mut dyn FnMut(Segment<'_>) -> core::fmt::Result,
+    ) -> core::fmt::Result {
+        let mut buf = Buf::new();
+        let magnitude = self.0.unsigned_abs();
+        if magnitude > 1 {
+            write!(buf, "{}", magnitude)?;
+        }
+        match self.0 {
+            0 => return Ok(()),
+            1.. => buf.write_char('+')?,
+            _ => buf.write_char('-')?,
+        }
+        sink(Segment::Superscript(buf.as_str()))
+    }
+}
+
+impl WriteSegments for Ion {
+    fn write_segments(
+        &self,
+        sink: &mut dyn FnMut(Segment<'_>) -> core::fmt::Result,
+    ) -> core::fmt::Result {
+        PlainFormula(self.formula).write_segments(sink)?;
+        Charge(self.charge).write_segments(sink)
+    }
+}
+
+impl WriteSegments for Complex<'_> {
+    fn write_segments(
+        &self,
+        sink: &mut dyn FnMut(Segment<'_>) -> core::fmt::Result,
+    ) -> core::fmt::Result {
+        sink(Segment::Text("["))?;
+        PlainFormula(self.center).write_segments(sink)?;
+        for ligand in self.ligands {
+            let (open, close) = if ligand.is_monatomic() {
+                ("", "")
+            } else if ligand.formula.contains('(') {
+                ("{", "}")
+            } else {
+                ("(", ")")
+            };
+            if !open.is_empty() {
+                sink(Segment::Text(open))?;
+            }
+            PlainFormula(ligand.formula).write_segments(sink)?;
+            if !close.is_empty() {
+                sink(Segment::Text(close))?;
+            }
+            if ligand.count > 1 {
+                Subscript(ligand.count).write_segments(sink)?;
+            }
+        }
+        sink(Segment::Text("]"))?;
+        Charge(self.charge).write_segments(sink)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    extern crate std;
+
+    #[test]
+    fn plain_formula_groups() {
+        assert_eq!(std::format!("{}", formula("Ca(OH)2")), "Ca(OH)₂");
+        assert_eq!(std::format!("{}", formula("C6H12O6")), "C₆H₁₂O₆");
+        assert_eq!(std::format!("{}", formula("2H2O")), "2H₂O");
+    }
+
+    #[test]
+    fn formula_builder() {
+        use Part::*;
+
+        let nitrate = [Element("N", 1), Element("O", 3)];
+        let res = std::format!("{}", Formula::new(&[Element("Mg", 1), Group(&nitrate, 2)]));
+        assert_eq!(res, "Mg(NO₃)₂");
+        let res = std::format!(
+            "{}",
+            Formula::new(&[Element("Cu", 1), Hydrate(1, &nitrate)])
+        );
+        assert_eq!(res, "Cu·NO₃");
+        assert_eq!(std::format!("{}", Formula::new(&[])), "");
+        let ammonium = Formula::new(&[Element("N", 1), Element("H", 4)]).charge(1);
+        assert_eq!(std::format!("{}", ammonium), "NH₄⁺");
+        let alkane = [Element("CH", 3), Element("CH", 2)].repeat(9);
+        let res = std::format!("{}", Formula::new(&alkane));
+        assert_eq!(res, "CH₃CH₂".repeat(9));
+
+        let formula = Formula::new(&[Element("Fe", 1)]).charge(3);
+        assert_eq!(std::format!("{}", formula), "Fe³⁺");
+        let mut charge = std::string::String::new();
+        formula
+            .write_segments(&mut |segment| match segment {
+                Segment::Superscript(s) => charge.write_str(s),
+                _ => Ok(()),
+            })
+            .unwrap();
+        assert_eq!(charge, "3+");
+
+        let mut segments = std::vec::Vec::new();
+        let parts = [Group(&nitrate, 2), Hydrate(3, &nitrate)];
+        let formula = Formula::new(&parts);
+        let mut sink = |segment: Segment<'_>| {
+            segments.push(std::format!("{:?}", segment));
+            Ok(())
+        };
+        formula.write_segments(&mut sink).unwrap();
+        assert_eq!(segments.concat().matches("Subscript").count(), 3);
+        assert!(segments.concat().contains("Text(\"·3\")"));
+    }
+
+    #[test]
+    fn isotopes() {
+        assert_eq!(std::format!("{}", isotope("H", 3).atomic_number(1)), "³₁H");
+        assert_eq!(std::format!("{}", isotope("C", 14)), "¹⁴C");
+        let mut segments = std::vec::Vec::new();
+        isotope("Pu", 239)
+            .atomic_number(94)
+            .write_segments(&mut |segment| {
+                segments.push(std::format!("{:?}", segment));
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(
+            segments,
+            ["Superscript(\"239\")", "Subscript(\"94\")", "Text(\"Pu\")"]
+        );
+    }
+
+    #[test]
+    fn equations() {
+        let res = std::format!("{}", equation("CH4 + 2O2  ->  CO2 + 2H2O"));
+        assert_eq!(res, "CH₄ + 2O₂  →  CO₂ + 2H₂O");
+        let res = std::format!("{}", equation("Na+ + Cl- <- NaCl"));
+        assert_eq!(res, "Na⁺ + Cl⁻ ← NaCl");
+        let res = std::format!("{}", equation("Cu^2+ + SO4^2- = - +"));
+        assert_eq!(res, "Cu²⁺ + SO₄²⁻ = - +");
+        assert_eq!(std::format!("{}", equation("A <-> B^x+")), "A ↔ B^x⁺");
+    }
+
+    #[test]
+    fn ion_lookup() {
+        assert_eq!(std::format!("{}", ion("Ammonium").unwrap()), "NH₄⁺");
+        assert_eq!(std::format!("{}", ion("phosphate").unwrap()), "PO₄³⁻");
+        assert_eq!(std::format!("{}", ion("mercury(I)").unwrap()), "Hg₂²⁺");
+        assert_eq!(ion("unobtainium"), None);
+    }
+
+    #[test]
+    fn complex_charge_and_abbreviations() {
+        let ligands = [Ligand::new("NH3", 4)];
+        let res = std::format!("{}", Complex::new("Cu", &ligands, 2));
+        assert_eq!(res, "[Cu(NH₃)₄]²⁺");
+
+        let ligands = [Ligand::new("en", 3)];
+        let res = std::format!("{}", Complex::new("Co", &ligands, 3));
+        assert_eq!(res, "[Co(en)₃]³⁺");
+
+        let ligands = [Ligand::new("NH3", 5), Ligand::new("Cl", 1)];
+        let res = std::format!("{}", Complex::new("Co", &ligands, 2));
+        assert_eq!(res, "[Co(NH₃)₅Cl]²⁺");
+    }
+}
@@ -0,0 +1,156 @@
+//! Locale dependent ordinal indicators following the plural rules of the
+//! [Unicode CLDR](https://cldr.unicode.org/index/cldr-spec/plural-rules).
+//!
+//! This module requires the `cldr` feature.
+//!
+//! ```
+//! use indexing_fmt::cldr::*;
+//!
+//! assert_eq!(format!("{}", ordinal(22, Locale::English)), "22ⁿᵈ");
+//! assert_eq!(format!("{}", ordinal(1, Locale::French)), "1ᵉʳ");
+//! assert_eq!(format!("{}", ordinal(3, Locale::Spanish)), "3º");
+//! ```
+
+use core::fmt::Display;
+
+/// Languages for which ordinal rules are available.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Locale {
+    Dutch,
+    English,
+    French,
+    German,
+    Italian,
+    Portuguese,
+    Spanish,
+    Swedish,
+}
+
+/// The ordinal plural categories defined by CLDR.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum OrdinalCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl Locale {
+    /// Selects the ordinal category of a number.
+    ///
+    /// ```
+    /// # use indexing_fmt::cldr::*;
+    /// assert_eq!(Locale::English.ordinal_category(13), OrdinalCategory::Other);
+    /// assert_eq!(Locale::English.ordinal_category(23), OrdinalCategory::Few);
+    /// assert_eq!(Locale::Italian.ordinal_category(800), OrdinalCategory::Many);
+    /// ```
+    pub fn ordinal_category(&self, n: u64) -> OrdinalCategory {
+        let (n10, n100) = (n % 10, n % 100);
+        match self {
+            Locale::English => match (n10, n100) {
+                (1, n100) if n100 != 11 => OrdinalCategory::One,
+                (2, n100) if n100 != 12 => OrdinalCategory::Two,
+                (3, n100) if n100 != 13 => OrdinalCategory::Few,
+                _ => OrdinalCategory::Other,
+            },
+            Locale::French => match n {
+                1 => OrdinalCategory::One,
+                _ => OrdinalCategory::Other,
+            },
+            Locale::Italian => match n {
+                8 | 11 | 80 | 800 => OrdinalCategory::Many,
+                _ => OrdinalCategory::Other,
+            },
+            Locale::Swedish => match (n10, n100) {
+                (1 | 2, n100) if n100 != 11 && n100 != 12 => OrdinalCategory::One,
+                _ => OrdinalCategory::Other,
+            },
+            Locale::Dutch | Locale::German | Locale::Portuguese | Locale::Spanish => {
+                OrdinalCategory::Other
+            }
+        }
+    }
+
+    /// The indicator which is appended to a number of the given category.
+    ///
+    /// Where the language uses superscript indicators, the superscript forms are returned, unless
+    /// [plain mode](crate::plain_mode) is enabled.
+    pub fn ordinal_suffix(&self, category: OrdinalCategory) -> &'static str {
+        if crate::plain_mode() {
+            return match (self, category) {
+                (Locale::English, OrdinalCategory::One) => "st",
+                (Locale::English, OrdinalCategory::Two) => "nd",
+                (Locale::English, OrdinalCategory::Few) => "rd",
+                (Locale::English, _) => "th",
+                (Locale::French, OrdinalCategory::One) => "er",
+                (Locale::French | Locale::Dutch, _) => "e",
+                (Locale::Italian | Locale::Portuguese | Locale::Spanish, _) => "o",
+                (Locale::German, _) => ".",
+                (Locale::Swedish, OrdinalCategory::One) => ":a",
+                (Locale::Swedish, _) => ":e",
+            };
+        }
+        match (self, category) {
+            (Locale::English, OrdinalCategory::One) => "\u{02e2}\u{1d57}",
+            (Locale::English, OrdinalCategory::Two) => "\u{207f}\u{1d48}",
+            (Locale::English, OrdinalCategory::Few) => "\u{02b3}\u{1d48}",
+            (Locale::English, _) => "\u{1d57}\u{02b0}",
+            (Locale::French, OrdinalCategory::One) => "\u{1d49}\u{02b3}",
+            (Locale::French, _) => "\u{1d49}",
+            (Locale::Italian | Locale::Portuguese | Locale::Spanish, _) => "\u{00ba}",
+            (Locale::Dutch, _) => "\u{1d49}",
+            (Locale::German, _) => ".",
+            (Locale::Swedish, OrdinalCategory::One) => ":a",
+            (Locale::Swedish, _) => ":e",
+        }
+    }
+}
+
+/// Displays a number followed by the ordinal indicator of a [Locale].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LocalizedOrdinal {
+    pub value: u64,
+    pub locale: Locale,
+}
+
+/// See [LocalizedOrdinal].
+pub fn ordinal(value: u64, locale: Locale) -> LocalizedOrdinal {
+    LocalizedOrdinal { value, locale }
+}
+
+impl Display for LocalizedOrdinal {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let category = self.locale.ordinal_category(self.value);
+        write!(f, "{}{}", self.value, self.locale.ordinal_suffix(category))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    extern crate std;
+
+    #[test]
+    fn english_teens() {
+        let res: std::vec::Vec<_> = [1, 2, 3, 4, 11, 12, 13, 101, 111, 112]
+            .into_iter()
+            .map(|n| std::format!("{}", ordinal(n, Locale::English)))
+            .collect();
+        assert_eq!(
+            res,
+            [
+                "1ˢᵗ", "2ⁿᵈ", "3ʳᵈ", "4ᵗʰ", "11ᵗʰ", "12ᵗʰ", "13ᵗʰ", "101ˢᵗ", "111ᵗʰ", "112ᵗʰ"
+            ]
+        );
+    }
+
+    #[test]
+    fn other_locales() {
+        assert_eq!(std::format!("{}", ordinal(2, Locale::French)), "2ᵉ");
+        assert_eq!(std::format!("{}", ordinal(3, Locale::German)), "3.");
+        assert_eq!(std::format!("{}", ordinal(21, Locale::Swedish)), "21:a");
+        assert_eq!(std::format!("{}", ordinal(11, Locale::Swedish)), "11:e");
+    }
+}
@@ -0,0 +1,170 @@
+//! Graceful degradation for fonts which lack some script glyphs.
+//!
+//! [Coverage] asks a callback whether the target font has a glyph for each character. A run of
+//! superscripts or subscripts in which any character has no glyph is written in ASCII as a whole,
+//! as `^2`, `^(12)`, `_3` or `_(ij)`, so that limited fonts never display replacement boxes nor
+//! mix both forms within one number. Runs longer than 32 characters are decided in parts.
+//!
+//! ```
+//! use indexing_fmt::*;
+//! use indexing_fmt::coverage::Coverage;
+//!
+//! // A font which only contains ASCII and the Latin-1 superscripts ¹²³
+//! let has_glyph = |c: char| c.is_ascii() || matches!(c, '¹' | '²' | '³');
+//! assert_eq!(format!("{}", Coverage::new("x²", has_glyph)), "x²");
+//! assert_eq!(format!("{}", Coverage::new("x⁴", has_glyph)), "x^4");
+//! assert_eq!(format!("{}", Coverage::new(Superscript(-45), has_glyph)), "^(-45)");
+//! ```
+
+use core::fmt::{Display, Write};
+
+use crate::Fallback;
+use crate::ScriptPosition;
+use crate::chars::baseline_char;
+
+/// Displays a value and replaces characters for which the font has no glyph.
+///
+/// See the [module](self) level documentation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Coverage<D, F> {
+    value: D,
+    has_glyph: F,
+    fallback: Fallback,
+}
+
+impl<D, F: Fn(char) -> bool> Coverage<D, F> {
+    pub fn new(value: D, has_glyph: F) -> Self {
+        Coverage {
+            value,
+            has_glyph,
+            fallback: Fallback::Keep,
+        }
+    }
+
+    /// Determines what happens to characters without a glyph which are no scripts, or whose
+    /// ASCII form is missing from the font as well.
+    pub fn fallback(self, fallback: Fallback) -> Self {
+        Coverage { fallback, ..self }
+    }
+}
+
+/// The number of script characters which are held back to decide how their run is written.
+const RUN_CAPACITY: usize = 32;
+
+/// Rewrites the characters and buffers the current run of scripts.
+struct Degrade<'a, W, F> {
+    inner: &'a mut W,
+    has_glyph: &'a F,
+    fallback: Fallback,
+    run: Option<ScriptPosition>,
+    buffer: [char; RUN_CAPACITY],
+    len: usize,
+    /// Whether a character of the current run has no glyph.
+    missing: bool,
+}
+
+impl<W: Write, F: Fn(char) -> bool> Degrade<'_, W, F> {
+    fn write_plain(&mut self, c: char) -> core::fmt::Result {
+        match (self.has_glyph)(c) {
+            true => self.inner.write_char(c),
+            false => self.fallback.write(self.inner, c),
+        }
+    }
+
+    fn end_run(&mut self) -> core::fmt::Result {
+        let Some(position) = self.run.take() else {
+            return Ok(());
+        };
+        let len = core::mem::take(&mut self.len);
+        let buffer = self.buffer;
+        if !core::mem::take(&mut self.missing) {
+            return buffer[..len]
+                .iter()
+                .try_for_each(|c| self.inner.write_char(*c));
+        }
+        self.write_plain(match position {
+            ScriptPosition::Superscript => '^',
+            ScriptPosition::Subscript => '_',
+        })?;
+        if len > 1 {
+            self.write_plain('(')?;
+        }
+        for c in &buffer[..len] {
+            self.write_plain(baseline_char(*c).map_or(*c, |(base, _)| base))?;
+        }
+        match len > 1 {
+            true => self.write_plain(')'),
+            false => Ok(()),
+        }
+    }
+}
+
+impl<W: Write, F: Fn(char) -> bool> Write for Degrade<'_, W, F> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        s.chars().try_for_each(|c| self.write_char(c))
+    }
+
+    fn write_char(&mut self, c: char) -> core::fmt::Result {
+        let Some((_, position)) = baseline_char(c) else {
+            self.end_run()?;
+            return self.write_plain(c);
+        };
+        if self.run != Some(position) || self.len == RUN_CAPACITY {
+            self.end_run()?;
+            self.run = Some(position);
+        }
+        self.buffer[self.len] = c;
+        self.len += 1;
+        self.missing |= !(self.has_glyph)(c);
+        Ok(())
+    }
+}
+
+impl<D: Display, F: Fn(char) -> bool> Display for Coverage<D, F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut degrade = Degrade {
+            inner: f,
+            has_glyph: &self.has_glyph,
+            fallback: self.fallback,
+            run: None,
+            buffer: ['\0'; RUN_CAPACITY],
+            len: 0,
+            missing: false,
+        };
+        write!(degrade, "{}", self.value)?;
+        degrade.end_run()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Superscript;
+    use crate::chem::formula;
+    extern crate std;
+
+    #[test]
+    fn mixed_runs_and_fallback() {
+        let ascii = |c: char| c.is_ascii();
+        let res = std::format!("{}", Coverage::new("xᵢⱼ²", ascii));
+        assert_eq!(res, "x_(ij)^2");
+        let res = std::format!("{}", Coverage::new(formula("H2O"), ascii));
+        assert_eq!(res, "H_2O");
+        let res = Coverage::new("α₁", ascii).fallback(Fallback::Replace('?'));
+        assert_eq!(std::format!("{}", res), "?_1");
+    }
+
+    #[test]
+    fn mixed_coverage_degrades_whole_run() {
+        let latin1 = |c: char| c.is_ascii() || matches!(c, '¹' | '²' | '³');
+        assert_eq!(std::format!("{}", Coverage::new("x⁴¹", latin1)), "x^(41)");
+        assert_eq!(std::format!("{}", Coverage::new("x¹⁴", latin1)), "x^(14)");
+        assert_eq!(std::format!("{}", Coverage::new("x¹²₄", latin1)), "x¹²_4");
+        let res = std::format!("{}", Coverage::new(Superscript(2024), latin1));
+        assert_eq!(res, "^(2024)");
+        assert_eq!(
+            std::format!("{}", Coverage::new(Superscript(312), latin1)),
+            "³¹²"
+        );
+    }
+}
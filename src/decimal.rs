@@ -0,0 +1,267 @@
+use core::fmt::Write;
+
+use crate::sci::Rounding;
+
+/// Maximum number of significant digits which can be stored.
+///
+/// This is large enough to hold every [u128] exactly.
+pub(crate) const MAX_DIGITS: usize = 40;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Kind {
+    Finite,
+    Nan,
+    Infinite,
+}
+
+/// Decimal representation of a number `d₀.d₁d₂… × 10ᵉ`.
+///
+/// Floats are converted using their shortest round-trip representation such that `0.1` is
+/// represented by the single digit `1` and not by the exact binary value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Decimal {
+    pub(crate) negative: bool,
+    pub(crate) kind: Kind,
+    digits: [u8; MAX_DIGITS],
+    len: usize,
+    pub(crate) exponent: i32,
+}
+
+/// Stack buffer used to capture the output of the formatting of [core].
+pub(crate) struct Buf {
+    bytes: [u8; 64],
+    len: usize,
+}
+
+impl Buf {
+    pub(crate) const fn new() -> Self {
+        Buf {
+            bytes: [0; 64],
+            len: 0,
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+}
+
+impl Write for Buf {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let end = self.len + s.len();
+        if end > self.bytes.len() {
+            return Err(core::fmt::Error);
+        }
+        self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+impl Decimal {
+    const ZERO: Decimal = Decimal {
+        negative: false,
+        kind: Kind::Finite,
+        digits: [0; MAX_DIGITS],
+        len: 0,
+        exponent: 0,
+    };
+
+    pub(crate) fn from_f64(value: f64) -> Self {
+        let mut decimal = Decimal {
+            negative: value.is_sign_negative(),
+            ..Decimal::ZERO
+        };
+        if value.is_nan() {
+            decimal.kind = Kind::Nan;
+            return decimal;
+        }
+        if value.is_infinite() {
+            decimal.kind = Kind::Infinite;
+            return decimal;
+        }
+
+        let mut buf = Buf::new();
+        // The shortest representation of a f64 never exceeds the buffer
+        let _ = write!(buf, "{:e}", value.abs());
+        let (mantissa, exponent) = buf.bytes[..buf.len].split_at(
+            buf.bytes[..buf.len]
+                .iter()
+                .position(|b| *b == b'e')
+                .unwrap_or(buf.len),
+        );
+        for b in mantissa.iter().filter(|b| b.is_ascii_digit()) {
+            decimal.digits[decimal.len] = b - b'0';
+            decimal.len += 1;
+        }
+        let mut exp_negative = false;
+        for b in exponent.iter().skip(1) {
+            match b {
+                b'-' => exp_negative = true,
+                b => decimal.exponent = 10 * decimal.exponent + (b - b'0') as i32,
+            }
+        }
+        if exp_negative {
+            decimal.exponent = -decimal.exponent;
+        }
+        decimal.normalize()
+    }
+
+    /// Represents the value `value × 10^exponent` exactly.
+    pub(crate) fn from_u128(value: u128, exponent: i32) -> Self {
+        let mut decimal = Decimal::ZERO;
+        let mut reversed = [0; MAX_DIGITS];
+        let mut value = value;
+        while value > 0 {
+            reversed[decimal.len] = (value % 10) as u8;
+            decimal.len += 1;
+            value /= 10;
+        }
+        for n in 0..decimal.len {
+            decimal.digits[n] = reversed[decimal.len - 1 - n];
+        }
+        decimal.exponent = exponent.saturating_add(decimal.len as i32 - 1);
+        decimal.normalize()
+    }
+
+    /// Strips trailing zeros and resets the exponent of zero.
+    fn normalize(mut self) -> Self {
+        while self.len > 0 && self.digits[self.len - 1] == 0 {
+            self.len -= 1;
+        }
+        if self.len == 0 {
+            self.exponent = 0;
+        }
+        self
+    }
+
+    pub(crate) fn is_zero(&self) -> bool {
+        self.kind == Kind::Finite && self.digits[..self.len].iter().all(|d| *d == 0)
+    }
+
+    /// Multiplies the value by `10^shift`, saturating the exponent at the bounds of [i32].
+    pub(crate) fn shifted(mut self, shift: i32) -> Self {
+        if self.len > 0 {
+            self.exponent = self.exponent.saturating_add(shift);
+        }
+        self
+    }
+
+    /// Converts back to the nearest [f64].
+    pub(crate) fn to_f64(self) -> f64 {
+        let value = match self.kind {
+            Kind::Nan => f64::NAN,
+            Kind::Infinite => f64::INFINITY,
+            Kind::Finite => {
+                let mut buf = Buf::new();
+                let _ = self.write_mantissa(&mut buf, '.');
+                let _ = write!(buf, "e{}", self.exponent);
+                buf.as_str().parse().unwrap_or(f64::NAN)
+            }
+        };
+        if self.negative { -value } else { value }
+    }
+
+    /// Finds the shortest rounding of the value for which `round_trips` holds.
+    ///
+    /// This is used for floating point types which are narrower than [f64]: their exact value as
+    /// [f64] has many more digits than are needed to identify them.
+    #[cfg(feature = "half")]
+    pub(crate) fn from_f64_shortest(value: f64, round_trips: impl Fn(f64) -> bool) -> Self {
+        let exact = Decimal::from_f64(value);
+        (1..exact.len)
+            .map(|n| exact.rounded(n, Rounding::HalfEven).normalize())
+            .find(|decimal| round_trips(decimal.to_f64()))
+            .unwrap_or(exact)
+    }
+
+    /// Rounds to exactly `significant` digits, padding with zeros if necessary.
+    pub(crate) fn rounded(mut self, significant: usize, rounding: Rounding) -> Self {
+        if self.kind != Kind::Finite {
+            return self;
+        }
+        let significant = significant.clamp(1, MAX_DIGITS);
+        if self.len > significant {
+            let first_dropped = self.digits[significant];
+            let rest_nonzero = self.digits[significant + 1..self.len]
+                .iter()
+                .any(|d| *d != 0);
+            let round_up = match rounding {
+                Rounding::HalfEven => {
+                    first_dropped > 5
+                        || (first_dropped == 5
+                            && (rest_nonzero || self.digits[significant - 1] % 2 == 1))
+                }
+                Rounding::HalfUp => first_dropped >= 5,
+                Rounding::TowardZero => false,
+            };
+            self.len = significant;
+            if round_up {
+                self.increment();
+            }
+        }
+        for n in self.len..significant {
+            self.digits[n] = 0;
+        }
+        if self.len == 0 {
+            self.exponent = 0;
+        }
+        self.len = significant;
+        self
+    }
+
+    /// Adds one unit in the last place.
+    fn increment(&mut self) {
+        for n in (0..self.len).rev() {
+            if self.digits[n] < 9 {
+                self.digits[n] += 1;
+                return;
+            }
+            self.digits[n] = 0;
+        }
+        self.digits[0] = 1;
+        self.exponent = self.exponent.saturating_add(1);
+    }
+
+    /// Writes the digits as `d₀.d₁d₂…` without sign.
+    pub(crate) fn write_mantissa(&self, f: &mut impl Write, separator: char) -> core::fmt::Result {
+        if self.len == 0 {
+            return f.write_char('0');
+        }
+        f.write_char((b'0' + self.digits[0]) as char)?;
+        if self.len > 1 {
+            f.write_char(separator)?;
+            for d in &self.digits[1..self.len] {
+                f.write_char((b'0' + d) as char)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the value in positional notation without sign.
+    pub(crate) fn write_fixed(&self, f: &mut impl Write, separator: char) -> core::fmt::Result {
+        if self.len == 0 {
+            return self.write_mantissa(f, separator);
+        }
+        let digit = |n: i32| {
+            if n >= 0 && (n as usize) < self.len {
+                (b'0' + self.digits[n as usize]) as char
+            } else {
+                '0'
+            }
+        };
+        let integer_digits = self.exponent.max(0) + 1;
+        let offset = self.exponent.min(0);
+        for n in 0..integer_digits {
+            f.write_char(digit(n + offset))?;
+        }
+        let fraction_digits = self.len as i32 - 1 - self.exponent;
+        if fraction_digits > 0 {
+            f.write_char(separator)?;
+            for n in 0..fraction_digits {
+                f.write_char(digit(integer_digits + offset + n))?;
+            }
+        }
+        Ok(())
+    }
+}
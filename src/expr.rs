@@ -0,0 +1,218 @@
+//! Small expressions of numbers, symbols, signs and parentheses written as scripts.
+//!
+//! Expressions which already are text can be converted with `to_superscript().strict()` or
+//! `to_subscript().strict()` instead.
+//!
+//! ```
+//! use indexing_fmt::expr::*;
+//!
+//! let exponent = [Token::Open, Token::Symbol("n"), Token::Plus, Token::Int(1), Token::Close];
+//! assert_eq!(format!("x{}", superscript(&exponent).validate()?), "x⁽ⁿ⁺¹⁾");
+//! assert_eq!(format!("10{}", superscript(&[Token::Int(-3)])), "10⁻³");
+//!
+//! let index = [Token::Symbol("k"), Token::Minus, Token::Int(2)];
+//! assert_eq!(format!("F{}", subscript(&index).validate()?), "Fₖ₋₂");
+//! # Ok::<(), ExpressionError>(())
+//! ```
+
+use core::fmt::{Display, Write};
+
+use crate::chars::{UnicodeVersion, script_char};
+use crate::{ScriptPosition, Subscript, Superscript};
+
+/// A single element of an [Expression].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Token<'a> {
+    /// An integer, written with a leading minus sign if negative.
+    Int(i128),
+    /// A name made of letters, digits or any other characters with script form.
+    Symbol(&'a str),
+    /// `+`
+    Plus,
+    /// `-`
+    Minus,
+    /// `=`
+    Equals,
+    /// `(`
+    Open,
+    /// `)`
+    Close,
+}
+
+/// Reasons why an [Expression] cannot be written.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ExpressionError {
+    /// The character of a [Token::Symbol] has no script form.
+    Unmappable(char),
+    /// A parenthesis is closed without being opened or opened without being closed.
+    Unbalanced,
+}
+
+impl Display for ExpressionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ExpressionError::Unmappable(c) => write!(f, "{:?} has no script form", c),
+            ExpressionError::Unbalanced => f.write_str("unbalanced parentheses"),
+        }
+    }
+}
+
+impl core::error::Error for ExpressionError {}
+
+/// Displays a sequence of tokens as scripts.
+///
+/// Characters of symbols without script form are written unchanged. Use
+/// [validate](Expression::validate) to reject them, together with unbalanced parentheses, before
+/// formatting.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Expression<'a> {
+    tokens: &'a [Token<'a>],
+    position: ScriptPosition,
+}
+
+/// Writes the tokens as superscripts.
+pub fn superscript<'a>(tokens: &'a [Token<'a>]) -> Expression<'a> {
+    Expression {
+        tokens,
+        position: ScriptPosition::Superscript,
+    }
+}
+
+/// Writes the tokens as subscripts.
+///
+/// ```
+/// # use indexing_fmt::expr::*;
+/// let index = [Token::Open, Token::Symbol("n"), Token::Plus, Token::Int(1), Token::Close];
+/// assert_eq!(format!("a{}", subscript(&index).validate()?), "a₍ₙ₊₁₎");
+/// # Ok::<(), ExpressionError>(())
+/// ```
+pub fn subscript<'a>(tokens: &'a [Token<'a>]) -> Expression<'a> {
+    Expression {
+        tokens,
+        position: ScriptPosition::Subscript,
+    }
+}
+
+impl Expression<'_> {
+    /// Checks that the expression can be written with script characters only.
+    ///
+    /// ```
+    /// # use indexing_fmt::expr::*;
+    /// let tokens = [Token::Symbol("q"), Token::Close];
+    /// assert_eq!(superscript(&tokens).validate(), Err(ExpressionError::Unmappable('q')));
+    /// assert_eq!(superscript(&tokens[1..]).validate(), Err(ExpressionError::Unbalanced));
+    /// ```
+    pub fn validate(self) -> Result<Self, ExpressionError> {
+        let mut depth = 0usize;
+        for token in self.tokens {
+            match token {
+                Token::Symbol(symbol) => {
+                    let unmappable = symbol
+                        .chars()
+                        .find(|c| script_char(*c, self.position, UnicodeVersion::V6_0).is_none());
+                    if let Some(c) = unmappable {
+                        return Err(ExpressionError::Unmappable(c));
+                    }
+                }
+                Token::Open => depth += 1,
+                Token::Close => {
+                    depth = depth.checked_sub(1).ok_or(ExpressionError::Unbalanced)?;
+                }
+                Token::Int(_) | Token::Plus | Token::Minus | Token::Equals => {}
+            }
+        }
+        match depth {
+            0 => Ok(self),
+            _ => Err(ExpressionError::Unbalanced),
+        }
+    }
+}
+
+/// The tokens are written without the flags of the formatter, so that `{:+}` or `{:03}` do not
+/// change every integer. A width pads the whole expression, which is aligned to the left by default
+/// like text.
+///
+/// ```
+/// # use indexing_fmt::expr::*;
+/// let exponent = [Token::Int(2), Token::Minus, Token::Symbol("n")];
+/// assert_eq!(format!("x{:>5}|", superscript(&exponent)), "x  ²⁻ⁿ|");
+/// ```
+impl Display for Expression<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        crate::pad(f, core::fmt::Alignment::Left, |w| self.write_tokens(w))
+    }
+}
+
+impl Expression<'_> {
+    fn write_tokens(&self, w: &mut dyn Write) -> core::fmt::Result {
+        let map = |w: &mut dyn Write, c: char| {
+            let s = script_char(c, self.position, UnicodeVersion::V6_0);
+            w.write_char(s.unwrap_or(c))
+        };
+        for token in self.tokens {
+            match token {
+                Token::Int(value) => match self.position {
+                    ScriptPosition::Superscript => write!(w, "{}", Superscript(*value))?,
+                    ScriptPosition::Subscript => write!(w, "{}", Subscript(*value))?,
+                },
+                Token::Symbol(symbol) => symbol.chars().try_for_each(|c| map(w, c))?,
+                Token::Plus => map(w, '+')?,
+                Token::Minus => map(w, '-')?,
+                Token::Equals => map(w, '=')?,
+                Token::Open => map(w, '(')?,
+                Token::Close => map(w, ')')?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    extern crate std;
+
+    #[test]
+    fn tokens() {
+        let tokens = [
+            Token::Symbol("a"),
+            Token::Equals,
+            Token::Open,
+            Token::Int(12),
+            Token::Minus,
+            Token::Symbol("k"),
+            Token::Close,
+        ];
+        let res = std::format!("{}", superscript(&tokens).validate().unwrap());
+        assert_eq!(res, "ᵃ⁼⁽¹²⁻ᵏ⁾");
+        assert_eq!(
+            std::format!("{}", superscript(&[Token::Symbol("2q")])),
+            "²q"
+        );
+        assert_eq!(
+            superscript(&tokens[..6]).validate(),
+            Err(ExpressionError::Unbalanced)
+        );
+    }
+
+    #[test]
+    fn subscripts() {
+        let tokens = [Token::Symbol("b"), Token::Equals, Token::Int(-7)];
+        assert_eq!(std::format!("{}", subscript(&tokens)), "b₌₋₇");
+        let res = subscript(&tokens).validate();
+        assert_eq!(res, Err(ExpressionError::Unmappable('b')));
+        assert_eq!(superscript(&tokens).validate(), Ok(superscript(&tokens)));
+    }
+
+    #[test]
+    fn flags_apply_to_whole_expression() {
+        let tokens = [Token::Int(1), Token::Plus, Token::Int(-23)];
+        let expression = superscript(&tokens);
+        assert_eq!(std::format!("{:+}", expression), "¹⁺⁻²³");
+        assert_eq!(std::format!("{:03}", expression), "¹⁺⁻²³");
+        assert_eq!(std::format!("{:#}", expression), "¹⁺⁻²³");
+        assert_eq!(std::format!("[{:>7}]", expression), "[  ¹⁺⁻²³]");
+        assert_eq!(std::format!("[{:*^8}]", expression), "[*¹⁺⁻²³**]");
+        assert_eq!(std::format!("[{:4}]", subscript(&tokens[..1])), "[₁   ]");
+    }
+}
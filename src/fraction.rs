@@ -0,0 +1,272 @@
+//! Fractions written with a superscript numerator and a subscript denominator.
+//!
+//! ```
+//! use indexing_fmt::fraction::*;
+//!
+//! assert_eq!(format!("{}", frac(1, 2)), "¹⁄₂");
+//! assert_eq!(format!("{} cup", frac(3, 4).vulgar()), "¾ cup");
+//! assert_eq!(format!("{} in", mixed(1, 7, 16)), "1⁷⁄₁₆ in");
+//! ```
+
+use core::fmt::{Display, Write};
+
+use crate::{FormatSubscript, FormatSuperscript};
+
+/// U+2044 FRACTION SLASH
+const FRACTION_SLASH: char = '\u{2044}';
+
+/// The precomposed vulgar fractions of Unicode as `(numerator, denominator, character)`.
+const VULGAR: [(u128, u128, char); 19] = [
+    (1, 2, '½'),
+    (1, 3, '⅓'),
+    (2, 3, '⅔'),
+    (1, 4, '¼'),
+    (3, 4, '¾'),
+    (1, 5, '⅕'),
+    (2, 5, '⅖'),
+    (3, 5, '⅗'),
+    (4, 5, '⅘'),
+    (1, 6, '⅙'),
+    (5, 6, '⅚'),
+    (1, 7, '⅐'),
+    (1, 8, '⅛'),
+    (3, 8, '⅜'),
+    (5, 8, '⅝'),
+    (7, 8, '⅞'),
+    (1, 9, '⅑'),
+    (1, 10, '⅒'),
+    (0, 3, '↉'),
+];
+
+/// The precomposed character of a fraction such as `½`, if one exists.
+///
+/// The fraction is not reduced, such that `2/4` has no character.
+///
+/// ```
+/// # use indexing_fmt::fraction::*;
+/// assert_eq!(vulgar_fraction(5, 8), Some('⅝'));
+/// assert_eq!(vulgar_fraction(2, 4), None);
+/// ```
+pub fn vulgar_fraction(numerator: u128, denominator: u128) -> Option<char> {
+    VULGAR
+        .iter()
+        .find(|(n, d, _)| *n == numerator && *d == denominator)
+        .map(|(_, _, c)| *c)
+}
+
+/// Displays a fraction like `¹⁄₂`.
+///
+/// The sign of a negative fraction is written as `-` in front of the fraction. The fraction is
+/// written as it is given and never reduced.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Fraction {
+    negative: bool,
+    numerator: u128,
+    denominator: u128,
+    vulgar: bool,
+}
+
+/// Starts a [Fraction].
+pub fn frac(numerator: i128, denominator: u128) -> Fraction {
+    Fraction {
+        negative: numerator < 0,
+        numerator: numerator.unsigned_abs(),
+        denominator,
+        vulgar: false,
+    }
+}
+
+impl Fraction {
+    /// Writes precomposed vulgar fractions such as `½` where they exist.
+    ///
+    /// ```
+    /// # use indexing_fmt::fraction::*;
+    /// assert_eq!(format!("{}", frac(-1, 3).vulgar()), "-⅓");
+    /// assert_eq!(format!("{}", frac(2, 7).vulgar()), "²⁄₇");
+    /// ```
+    pub fn vulgar(self) -> Self {
+        Fraction {
+            vulgar: true,
+            ..self
+        }
+    }
+}
+
+impl Display for Fraction {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.negative {
+            f.write_char('-')?;
+        }
+        let numerator = self.numerator;
+        let vulgar = vulgar_fraction(numerator, self.denominator);
+        match vulgar {
+            Some(c) if self.vulgar && !crate::plain_mode() => f.write_char(c),
+            _ => write!(
+                f,
+                "{}{}{}",
+                numerator.to_superscript(),
+                FRACTION_SLASH,
+                self.denominator.to_subscript()
+            ),
+        }
+    }
+}
+
+/// Displays a whole number followed by a fraction like `1¹⁄₂`.
+///
+/// The whole number is omitted if it is zero and the fraction is omitted if its numerator is zero,
+/// such that zero itself is written as `0`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MixedNumber {
+    negative: bool,
+    whole: u128,
+    fraction: Fraction,
+}
+
+/// Starts a [MixedNumber] from its parts.
+///
+/// The sign of the whole number applies to the fraction as well.
+pub fn mixed(whole: i128, numerator: u128, denominator: u128) -> MixedNumber {
+    MixedNumber {
+        negative: whole < 0,
+        whole: whole.unsigned_abs(),
+        fraction: Fraction {
+            negative: false,
+            numerator,
+            denominator,
+            vulgar: false,
+        },
+    }
+}
+
+impl MixedNumber {
+    /// Approximates the value by the closest fraction with at most the given denominator.
+    ///
+    /// Returns [None] if the value is not finite, does not fit into [u128] or if
+    /// `max_denominator` is zero.
+    ///
+    /// ```
+    /// # use indexing_fmt::fraction::*;
+    /// let cups = MixedNumber::from_f64(2.33, 8).unwrap();
+    /// assert_eq!(format!("{}", cups.vulgar()), "2⅓");
+    /// let inches = MixedNumber::from_f64(-0.4375, 16).unwrap();
+    /// assert_eq!(format!("{}", inches), "-⁷⁄₁₆");
+    /// ```
+    pub fn from_f64(value: f64, max_denominator: u128) -> Option<Self> {
+        if !value.is_finite() || max_denominator == 0 || value.abs() >= u128::MAX as f64 {
+            return None;
+        }
+        let magnitude = value.abs();
+        let mut whole = magnitude as u128;
+        let fract = (magnitude - whole as f64).max(0.0);
+        let (mut numerator, denominator) = approximate(fract, max_denominator);
+        if numerator == denominator {
+            whole += 1;
+            numerator = 0;
+        }
+        let negative = value < 0.0 && (whole > 0 || numerator > 0);
+        Some(MixedNumber {
+            negative,
+            whole,
+            fraction: Fraction {
+                negative: false,
+                numerator,
+                denominator,
+                vulgar: false,
+            },
+        })
+    }
+
+    /// Writes precomposed vulgar fractions such as `½` where they exist.
+    pub fn vulgar(self) -> Self {
+        MixedNumber {
+            fraction: self.fraction.vulgar(),
+            ..self
+        }
+    }
+}
+
+/// Finds the closest fraction to `x` in `[0, 1)` with a denominator of at most `max`, using the
+/// convergents and semiconvergents of its continued fraction.
+fn approximate(x: f64, max: u128) -> (u128, u128) {
+    let (mut p0, mut q0, mut p1, mut q1) = (0u128, 1u128, 1u128, 0u128);
+    let mut rest = x;
+    loop {
+        let a = rest as u128;
+        let Some(q2) = a.checked_mul(q1).and_then(|q| q.checked_add(q0)) else {
+            break;
+        };
+        if q2 > max {
+            break;
+        }
+        (p0, q0, p1, q1) = (p1, q1, p0 + a * p1, q2);
+        let remainder = rest - a as f64;
+        if remainder <= 0.0 {
+            break;
+        }
+        rest = 1.0 / remainder;
+    }
+    if q1 == 0 {
+        return (p0, q0);
+    }
+    let k = (max - q0) / q1;
+    let (p, q) = (p0 + k * p1, q0 + k * q1);
+    let error = |n: u128, d: u128| (x - n as f64 / d as f64).abs();
+    match error(p, q) < error(p1, q1) {
+        true => (p, q),
+        false => (p1, q1),
+    }
+}
+
+impl Display for MixedNumber {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.negative {
+            f.write_char('-')?;
+        }
+        if self.whole > 0 || self.fraction.numerator == 0 {
+            write!(f, "{}", self.whole)?;
+        }
+        match self.fraction.numerator {
+            0 => Ok(()),
+            _ => write!(f, "{}", self.fraction),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    extern crate std;
+
+    #[test]
+    fn fractions() {
+        assert_eq!(std::format!("{}", frac(-22, 7)), "-²²⁄₇");
+        assert_eq!(std::format!("{}", frac(0, 3).vulgar()), "↉");
+        assert_eq!(std::format!("{}", frac(1, 0)), "¹⁄₀");
+        let res = std::format!("{}", frac(i128::MIN, 1));
+        assert_eq!(
+            res,
+            std::format!("-{}⁄₁", i128::MIN.unsigned_abs().to_superscript())
+        );
+        let all = VULGAR.map(|(n, d, _)| std::format!("{}", frac(n as i128, d).vulgar()));
+        assert_eq!(all.concat(), "½⅓⅔¼¾⅕⅖⅗⅘⅙⅚⅐⅛⅜⅝⅞⅑⅒↉");
+    }
+
+    #[test]
+    fn mixed_numbers() {
+        assert_eq!(std::format!("{}", mixed(-3, 1, 2).vulgar()), "-3½");
+        assert_eq!(std::format!("{}", mixed(0, 0, 4)), "0");
+        assert_eq!(std::format!("{}", mixed(5, 0, 4)), "5");
+        let res = std::format!("{}", mixed(1, u128::MAX, 2));
+        assert_eq!(res, std::format!("1{}⁄₂", u128::MAX.to_superscript()));
+        let mixed = |x, max| std::format!("{}", MixedNumber::from_f64(x, max).unwrap());
+        assert_eq!(mixed(1.999, 8), "2");
+        assert_eq!(mixed(-0.01, 8), "0");
+        assert_eq!(mixed(22.0 / 7.0, 10), "3¹⁄₇");
+        assert_eq!(mixed(3.2, 4), "3¹⁄₄");
+        assert_eq!(mixed(0.3, 1000), "³⁄₁₀");
+        assert_eq!(mixed(0.1, 1), "0");
+        assert_eq!(MixedNumber::from_f64(f64::NAN, 8), None);
+        assert_eq!(MixedNumber::from_f64(1.5, 0), None);
+    }
+}
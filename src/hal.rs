@@ -0,0 +1,207 @@
+//! Writing scripts directly to serial ports of [embedded_hal].
+//!
+//! This module requires the `embedded-hal` feature.
+//!
+//! ```
+//! use indexing_fmt::*;
+//! use indexing_fmt::hal::write_blocking;
+//! # use embedded_hal::serial;
+//! # struct Serial(Vec<u8>);
+//! # impl serial::Write<u8> for Serial {
+//! #     type Error = ();
+//! #     fn write(&mut self, word: u8) -> nb::Result<(), ()> {
+//! #         self.0.push(word);
+//! #         Ok(())
+//! #     }
+//! #     fn flush(&mut self) -> nb::Result<(), ()> {
+//! #         Ok(())
+//! #     }
+//! # }
+//!
+//! let mut serial = Serial(Vec::new());
+//! write_blocking(&mut serial, &12.to_subscript()).unwrap();
+//! assert_eq!(serial.0, "₁₂".as_bytes());
+//! ```
+
+use core::fmt::{Display, Write};
+
+use embedded_hal::serial;
+
+/// Forwards the UTF-8 bytes of the formatted output to a serial port.
+struct Adapter<'a, S: serial::Write<u8>> {
+    serial: &'a mut S,
+    /// Number of bytes which were sent in previous attempts and need to be skipped.
+    skip: usize,
+    position: usize,
+    block: bool,
+    error: Option<nb::Error<S::Error>>,
+}
+
+impl<S: serial::Write<u8>> Write for Adapter<'_, S> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        for byte in s.bytes() {
+            if self.position >= self.skip {
+                let result = match self.block {
+                    true => nb::block!(self.serial.write(byte)).map_err(nb::Error::Other),
+                    false => self.serial.write(byte),
+                };
+                if let Err(error) = result {
+                    self.error = Some(error);
+                    return Err(core::fmt::Error);
+                }
+            }
+            self.position += 1;
+        }
+        Ok(())
+    }
+}
+
+fn write_from<S, D>(
+    serial: &mut S,
+    value: &D,
+    skip: usize,
+    block: bool,
+) -> (usize, nb::Result<(), S::Error>)
+where
+    S: serial::Write<u8>,
+    D: Display + ?Sized,
+{
+    let mut adapter = Adapter {
+        serial,
+        skip,
+        position: 0,
+        block,
+        error: None,
+    };
+    let _ = write!(adapter, "{}", value);
+    match adapter.error {
+        Some(error) => (adapter.position, Err(error)),
+        None => (adapter.position, Ok(())),
+    }
+}
+
+/// Writes the formatted value, blocking while the serial port is busy.
+pub fn write_blocking<S, D>(serial: &mut S, value: &D) -> Result<(), S::Error>
+where
+    S: serial::Write<u8>,
+    D: Display + ?Sized,
+{
+    match write_from(serial, value, 0, true).1 {
+        Ok(()) => Ok(()),
+        Err(nb::Error::Other(error)) => Err(error),
+        Err(nb::Error::WouldBlock) => unreachable!(),
+    }
+}
+
+/// Writes a formatted value without blocking.
+///
+/// When the serial port would block, [nb::Error::WouldBlock] is returned and the writer remembers
+/// how many bytes were already sent. Calling [SerialWriter::poll] again with the same value
+/// resumes writing after these bytes.
+///
+/// ```
+/// # use indexing_fmt::*;
+/// # use indexing_fmt::hal::SerialWriter;
+/// # use embedded_hal::serial;
+/// # struct Serial(Vec<u8>, bool);
+/// # impl serial::Write<u8> for Serial {
+/// #     type Error = ();
+/// #     fn write(&mut self, word: u8) -> nb::Result<(), ()> {
+/// #         self.1 = !self.1;
+/// #         if self.1 { return Err(nb::Error::WouldBlock); }
+/// #         self.0.push(word);
+/// #         Ok(())
+/// #     }
+/// #     fn flush(&mut self) -> nb::Result<(), ()> {
+/// #         Ok(())
+/// #     }
+/// # }
+/// let mut serial = Serial(Vec::new(), false);
+/// let mut writer = SerialWriter::new();
+/// let label = 7.to_superscript();
+/// while let Err(nb::Error::WouldBlock) = writer.poll(&mut serial, &label) {
+///     // Do other work
+/// }
+/// assert_eq!(serial.0, "⁷".as_bytes());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SerialWriter {
+    written: usize,
+}
+
+impl SerialWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Continues writing the value. Once [Ok] is returned, the writer can be reused.
+    pub fn poll<S, D>(&mut self, serial: &mut S, value: &D) -> nb::Result<(), S::Error>
+    where
+        S: serial::Write<u8>,
+        D: Display + ?Sized,
+    {
+        let (position, result) = write_from(serial, value, self.written, false);
+        self.written = match result {
+            Err(nb::Error::WouldBlock) => position,
+            _ => 0,
+        };
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    extern crate std;
+
+    struct Flaky {
+        bytes: std::vec::Vec<u8>,
+        calls: usize,
+    }
+
+    impl serial::Write<u8> for Flaky {
+        type Error = ();
+
+        fn write(&mut self, word: u8) -> nb::Result<(), ()> {
+            self.calls += 1;
+            if self.calls % 3 == 0 {
+                return Err(nb::Error::WouldBlock);
+            }
+            if word == b'!' {
+                return Err(nb::Error::Other(()));
+            }
+            self.bytes.push(word);
+            Ok(())
+        }
+
+        fn flush(&mut self) -> nb::Result<(), ()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn resume_after_would_block() {
+        let mut serial = Flaky {
+            bytes: std::vec::Vec::new(),
+            calls: 0,
+        };
+        let mut writer = SerialWriter::new();
+        let value = crate::Superscript(-1234);
+        let mut attempts = 0;
+        while writer.poll(&mut serial, &value).is_err() {
+            attempts += 1;
+        }
+        assert!(attempts > 0);
+        assert_eq!(serial.bytes, "⁻¹²³⁴".as_bytes());
+    }
+
+    #[test]
+    fn blocking_reports_errors() {
+        let mut serial = Flaky {
+            bytes: std::vec::Vec::new(),
+            calls: 0,
+        };
+        assert_eq!(write_blocking(&mut serial, "ab!"), Err(()));
+        assert_eq!(serial.bytes, b"ab");
+    }
+}
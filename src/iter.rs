@@ -0,0 +1,159 @@
+//! Iteration over the characters of integer scripts and their lengths.
+
+use crate::{
+    SUBSCRIPT_DIGITS, SUPERSCRIPT_DIGITS, ScriptPosition, Subscript, Superscript, plain_mode,
+};
+
+/// Iterator over the characters of an integer script.
+///
+/// See [Superscript::chars] and [Subscript::chars].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ScriptChars {
+    sign: Option<char>,
+    magnitude: u128,
+    /// The number of digits which are still to come.
+    remaining: u32,
+    digits: [char; 10],
+}
+
+impl ScriptChars {
+    fn new(negative: bool, magnitude: u128, position: ScriptPosition) -> Self {
+        let (digits, minus) = match position {
+            _ if plain_mode() => (['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'], '-'),
+            ScriptPosition::Superscript => (SUPERSCRIPT_DIGITS, '\u{207b}'),
+            ScriptPosition::Subscript => (SUBSCRIPT_DIGITS, '\u{208b}'),
+        };
+        ScriptChars {
+            sign: Some(minus).filter(|_| negative),
+            magnitude,
+            remaining: crate::digit_count(magnitude, 10),
+            digits,
+        }
+    }
+}
+
+impl Iterator for ScriptChars {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        if let Some(sign) = self.sign.take() {
+            return Some(sign);
+        }
+        self.remaining = self.remaining.checked_sub(1)?;
+        let digit = self.magnitude / 10u128.pow(self.remaining) % 10;
+        Some(self.digits[digit as usize])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.remaining as usize + usize::from(self.sign.is_some());
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for ScriptChars {}
+
+impl core::iter::FusedIterator for ScriptChars {}
+
+macro_rules! impl_chars(
+    ($wrapper:ident, $position:expr, $($ty_unsigned:ty, $ty_signed:ty);* $(;)?) => {
+        $(
+            impl $wrapper<$ty_unsigned> {
+                /// Iterates over the characters which [Display](core::fmt::Display) writes
+                /// without formatting flags.
+                pub fn chars(&self) -> ScriptChars {
+                    ScriptChars::new(false, self.0 as u128, $position)
+                }
+            }
+
+            impl $wrapper<$ty_signed> {
+                /// Iterates over the characters which [Display](core::fmt::Display) writes
+                /// without formatting flags.
+                pub fn chars(&self) -> ScriptChars {
+                    ScriptChars::new(self.0 < 0, self.0.unsigned_abs() as u128, $position)
+                }
+            }
+        )*
+    };
+);
+
+impl_chars!(
+    Superscript, ScriptPosition::Superscript,
+    u128, i128; usize, isize; u64, i64; u32, i32; u16, i16; u8, i8;
+);
+impl_chars!(
+    Subscript, ScriptPosition::Subscript,
+    u128, i128; usize, isize; u64, i64; u32, i32; u16, i16; u8, i8;
+);
+
+/// Counts the characters and bytes written to it.
+#[derive(Default)]
+struct Length {
+    chars: usize,
+    bytes: usize,
+}
+
+impl core::fmt::Write for Length {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.chars += s.chars().count();
+        self.bytes += s.len();
+        Ok(())
+    }
+}
+
+macro_rules! impl_lengths(
+    ($wrapper:ident) => {
+        impl<T> $wrapper<T>
+        where
+            Self: core::fmt::Display,
+        {
+            fn length(&self) -> Length {
+                let mut length = Length::default();
+                let _ = core::fmt::Write::write_fmt(&mut length, format_args!("{}", self));
+                length
+            }
+
+            /// The number of characters written without formatting flags.
+            pub fn char_count(&self) -> usize {
+                self.length().chars
+            }
+
+            /// The number of UTF-8 bytes written without formatting flags.
+            pub fn byte_len(&self) -> usize {
+                self.length().bytes
+            }
+        }
+    };
+);
+
+impl_lengths!(Superscript);
+impl_lengths!(Subscript);
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    extern crate std;
+
+    #[test]
+    fn chars_match_display() {
+        for value in [0, 7, -12, i64::MIN, i64::MAX] {
+            let chars: std::string::String = value.to_superscript().chars().collect();
+            assert_eq!(chars, std::format!("{}", value.to_superscript()));
+        }
+        let mut chars = (-40i8).to_subscript().chars();
+        assert_eq!(chars.len(), 3);
+        assert_eq!(chars.next(), Some('₋'));
+        assert_eq!(chars.len(), 2);
+        assert_eq!(chars.by_ref().last(), Some('₀'));
+        assert_eq!(chars.next(), None);
+    }
+
+    #[test]
+    fn lengths() {
+        assert_eq!(123.to_superscript().char_count(), 3);
+        assert_eq!(123.to_superscript().byte_len(), 6);
+        assert_eq!(456.to_superscript().byte_len(), 9);
+        assert_eq!((-1.5).to_superscript().char_count(), 4);
+        assert_eq!((-5i8).to_subscript().byte_len(), 6);
+        assert_eq!(u128::MAX.to_subscript().char_count(), 39);
+    }
+}
@@ -0,0 +1,827 @@
+//! Generation of labels for numbered items.
+//!
+//! ```
+//! use indexing_fmt::label::*;
+//!
+//! let mut figures = Numbering::new("Figure");
+//! assert_eq!(format!("{}", figures.next_item()), "Figure 1");
+//! assert_eq!(format!("{}", figures.next_item()), "Figure 2");
+//! assert_eq!(format!("{}", figures.next_sub_item()), "Figure 2ᵃ");
+//! assert_eq!(format!("{}", figures.next_sub_item()), "Figure 2ᵇ");
+//! assert_eq!(format!("{}", figures.next_item()), "Figure 3");
+//! assert_eq!(format!("{}", figures.next_sub_item()), "Figure 3ᵃ");
+//! ```
+
+use core::fmt::{Display, Write};
+
+use crate::chars::{Fallback, Spacing, UnicodeVersion, script_char, split_trailing_digits};
+use crate::{ScriptPosition, Subscript, Superscript, from_subscript_char, from_superscript_char};
+
+/// Counting scheme of sub-items.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SubStyle {
+    /// `a, b, …, z, aa, ab, …`
+    #[default]
+    Letters,
+    /// `1, 2, 3, …`
+    Numbers,
+}
+
+/// Hands out hierarchical labels such as `Table 2ₐ` or `Figure 3ᵇ`.
+///
+/// Sub-item counters restart for every parent item. See the [module](self) level
+/// documentation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Numbering<'a> {
+    name: &'a str,
+    parent: u32,
+    child: u32,
+    style: SubStyle,
+    position: ScriptPosition,
+    fallback: Fallback,
+    unicode_version: UnicodeVersion,
+    spacing: Spacing,
+}
+
+impl<'a> Numbering<'a> {
+    /// Sub-item labels are superscript letters by default.
+    pub fn new(name: &'a str) -> Self {
+        Numbering {
+            name,
+            parent: 0,
+            child: 0,
+            style: SubStyle::Letters,
+            position: ScriptPosition::Superscript,
+            fallback: Fallback::Keep,
+            unicode_version: UnicodeVersion::V6_0,
+            spacing: Spacing::None,
+        }
+    }
+
+    pub fn style(self, style: SubStyle) -> Self {
+        Numbering { style, ..self }
+    }
+
+    pub fn superscript(self) -> Self {
+        Numbering {
+            position: ScriptPosition::Superscript,
+            ..self
+        }
+    }
+
+    pub fn subscript(self) -> Self {
+        Numbering {
+            position: ScriptPosition::Subscript,
+            ..self
+        }
+    }
+
+    /// Determines how letters without script form are written.
+    ///
+    /// Only few letters have a subscript form.
+    pub fn fallback(self, fallback: Fallback) -> Self {
+        Numbering { fallback, ..self }
+    }
+
+    /// See [Letters::unicode_version].
+    pub fn unicode_version(self, unicode_version: UnicodeVersion) -> Self {
+        Numbering {
+            unicode_version,
+            ..self
+        }
+    }
+
+    /// Inserts a space between the number of the parent and the sub-item.
+    ///
+    /// ```
+    /// # use indexing_fmt::label::*;
+    /// use indexing_fmt::Spacing;
+    ///
+    /// let mut tables = Numbering::new("Table").spacing(Spacing::Thin);
+    /// assert_eq!(format!("{}", tables.next_sub_item()), "Table 1\u{2009}ᵃ");
+    /// ```
+    pub fn spacing(self, spacing: Spacing) -> Self {
+        Numbering { spacing, ..self }
+    }
+
+    /// Advances to the next parent item.
+    pub fn next_item(&mut self) -> Label<'a> {
+        self.parent += 1;
+        self.child = 0;
+        self.label()
+    }
+
+    /// Advances to the next sub-item of the current parent.
+    pub fn next_sub_item(&mut self) -> Label<'a> {
+        self.parent = self.parent.max(1);
+        self.child += 1;
+        self.label()
+    }
+
+    fn label(&self) -> Label<'a> {
+        Label {
+            name: self.name,
+            parent: self.parent,
+            child: self.child,
+            style: self.style,
+            position: self.position,
+            fallback: self.fallback,
+            unicode_version: self.unicode_version,
+            spacing: self.spacing,
+        }
+    }
+}
+
+/// A label handed out by [Numbering].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Label<'a> {
+    name: &'a str,
+    parent: u32,
+    child: u32,
+    style: SubStyle,
+    position: ScriptPosition,
+    fallback: Fallback,
+    unicode_version: UnicodeVersion,
+    spacing: Spacing,
+}
+
+impl Label<'_> {
+    pub fn parent(&self) -> u32 {
+        self.parent
+    }
+
+    /// The number of the sub-item starting at one or [None] for parent items.
+    pub fn child(&self) -> Option<u32> {
+        (self.child > 0).then_some(self.child)
+    }
+}
+
+/// Writes the bijective base-26 representation `a, …, z, aa, ab, …` of a positive number.
+fn write_letters(
+    f: &mut impl Write,
+    n: u32,
+    map: &impl Fn(char) -> Option<char>,
+    fallback: Fallback,
+) -> core::fmt::Result {
+    if n > 26 {
+        write_letters(f, (n - 1) / 26, map, fallback)?;
+    }
+    let c = (b'a' + ((n - 1) % 26) as u8) as char;
+    match map(c) {
+        Some(s) => f.write_char(s),
+        None => fallback.write(f, c),
+    }
+}
+
+/// Displays a positive number as script letters `a, …, z, aa, ab, …` (bijective base 26).
+///
+/// Not every letter has a subscript form. The missing ones (`b c d f g q w y z`) are handled by
+/// the [Fallback], which keeps them on the baseline by default. Zero is displayed as nothing.
+///
+/// ```
+/// # use indexing_fmt::label::*;
+/// assert_eq!(format!("x{}", Letters::superscript(28)), "xᵃᵇ");
+/// assert_eq!(format!("x{}", Letters::subscript(31)), "xₐₑ");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Letters {
+    n: u32,
+    position: ScriptPosition,
+    fallback: Fallback,
+    unicode_version: UnicodeVersion,
+}
+
+impl Letters {
+    pub fn superscript(n: u32) -> Self {
+        Letters {
+            n,
+            position: ScriptPosition::Superscript,
+            fallback: Fallback::Keep,
+            unicode_version: UnicodeVersion::V6_0,
+        }
+    }
+
+    pub fn subscript(n: u32) -> Self {
+        Letters {
+            n,
+            position: ScriptPosition::Subscript,
+            fallback: Fallback::Keep,
+            unicode_version: UnicodeVersion::V6_0,
+        }
+    }
+
+    pub fn fallback(self, fallback: Fallback) -> Self {
+        Letters { fallback, ..self }
+    }
+
+    /// Restricts or extends the available letters, see [UnicodeVersion].
+    pub fn unicode_version(self, unicode_version: UnicodeVersion) -> Self {
+        Letters {
+            unicode_version,
+            ..self
+        }
+    }
+}
+
+impl Display for Letters {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.n == 0 {
+            return Ok(());
+        }
+        let map = |c| script_char(c, self.position, self.unicode_version);
+        write_letters(f, self.n, &map, self.fallback)
+    }
+}
+
+/// Iterates over the lettered labels `a, b, …, z, aa, ab, …` as [Letters].
+///
+/// ```
+/// # use indexing_fmt::label::*;
+/// use indexing_fmt::Fallback;
+///
+/// let labels: Vec<_> = LetterSequence::subscript()
+///     .fallback(Fallback::Replace('_'))
+///     .take(5)
+///     .map(|l| l.to_string())
+///     .collect();
+/// assert_eq!(labels, ["ₐ", "_", "_", "_", "ₑ"]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LetterSequence {
+    next: Letters,
+}
+
+impl LetterSequence {
+    pub fn superscript() -> Self {
+        LetterSequence {
+            next: Letters::superscript(1),
+        }
+    }
+
+    pub fn subscript() -> Self {
+        LetterSequence {
+            next: Letters::subscript(1),
+        }
+    }
+
+    /// See [Letters].
+    pub fn fallback(self, fallback: Fallback) -> Self {
+        LetterSequence {
+            next: self.next.fallback(fallback),
+        }
+    }
+
+    /// See [Letters::unicode_version].
+    pub fn unicode_version(self, unicode_version: UnicodeVersion) -> Self {
+        LetterSequence {
+            next: self.next.unicode_version(unicode_version),
+        }
+    }
+}
+
+impl Iterator for LetterSequence {
+    type Item = Letters;
+
+    fn next(&mut self) -> Option<Letters> {
+        let current = self.next;
+        self.next.n = current.n.checked_add(1)?;
+        Some(current)
+    }
+}
+
+impl Display for Label<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if !self.name.is_empty() {
+            write!(f, "{} ", self.name)?;
+        }
+        write!(f, "{}", self.parent)?;
+        if self.child == 0 {
+            return Ok(());
+        }
+        write!(f, "{}", self.spacing)?;
+        let letters = Letters {
+            n: self.child,
+            position: self.position,
+            fallback: self.fallback,
+            unicode_version: self.unicode_version,
+        };
+        match (self.style, self.position) {
+            (SubStyle::Letters, _) => write!(f, "{}", letters),
+            (SubStyle::Numbers, ScriptPosition::Superscript) => {
+                write!(f, "{}", Superscript(self.child))
+            }
+            (SubStyle::Numbers, ScriptPosition::Subscript) => {
+                write!(f, "{}", Subscript(self.child))
+            }
+        }
+    }
+}
+
+/// Displays a sort key for a label ending in superscript or subscript digits.
+///
+/// The key consists of the base of the label followed by the index as zero-padded ASCII digits.
+/// Sorting the keys as plain strings thus orders `node₁₀` after `node₂`. The width of the index
+/// defaults to 20 which fits every [u64].
+///
+/// ```
+/// # use indexing_fmt::label::*;
+/// assert_eq!(format!("{}", CollationKey::new("node₁₀").width(4)), "node0010");
+/// assert_eq!(format!("{}", CollationKey::new("node₂").width(4)), "node0002");
+/// assert_eq!(format!("{}", CollationKey::new("node").width(4)), "node");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CollationKey<'a> {
+    label: &'a str,
+    width: usize,
+}
+
+impl<'a> CollationKey<'a> {
+    pub fn new(label: &'a str) -> Self {
+        CollationKey { label, width: 20 }
+    }
+
+    /// Sets the number of digits of the index.
+    ///
+    /// Indices with more digits are written in full and may thus not be ordered correctly.
+    pub fn width(self, width: usize) -> Self {
+        CollationKey { width, ..self }
+    }
+}
+
+impl Display for CollationKey<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let (base, digits, position) = split_trailing_digits(self.label);
+        f.write_str(base)?;
+        let value = match position {
+            Some(ScriptPosition::Superscript) => from_superscript_char,
+            Some(ScriptPosition::Subscript) => from_subscript_char,
+            None => return Ok(()),
+        };
+        for _ in digits.chars().count()..self.width {
+            f.write_char('0')?;
+        }
+        for d in digits.chars().filter_map(value) {
+            f.write_char((b'0' + d) as char)?;
+        }
+        Ok(())
+    }
+}
+
+/// Compares labels such that trailing superscript or subscript indices are ordered numerically.
+///
+/// The bases are compared as strings first. Labels without index come before labels with the
+/// same base and an index. Ties such as `x₀₁` and `x₁` are broken by comparing the full strings.
+///
+/// ```
+/// # use indexing_fmt::label::*;
+/// let mut names = ["x₂", "x₁₀", "x₁", "x"];
+/// names.sort_by(|a, b| cmp_indexed(a, b));
+/// assert_eq!(names, ["x", "x₁", "x₂", "x₁₀"]);
+/// ```
+pub fn cmp_indexed(a: &str, b: &str) -> core::cmp::Ordering {
+    let index = |label| {
+        let (base, digits, position) = split_trailing_digits(label);
+        let value = match position {
+            Some(ScriptPosition::Superscript) => from_superscript_char,
+            Some(ScriptPosition::Subscript) => from_subscript_char,
+            None => return (base, None),
+        };
+        (
+            base,
+            Some(digits.chars().filter_map(value).skip_while(|d| *d == 0)),
+        )
+    };
+    let ((base_a, digits_a), (base_b, digits_b)) = (index(a), index(b));
+    base_a
+        .cmp(base_b)
+        .then_with(|| match (digits_a, digits_b) {
+            (Some(x), Some(y)) => x.clone().count().cmp(&y.clone().count()).then(x.cmp(y)),
+            (x, y) => x.is_some().cmp(&y.is_some()),
+        })
+        .then_with(|| a.cmp(b))
+}
+
+/// A label which is ordered by [cmp_indexed], e.g. as key of a [BTreeMap].
+///
+/// ```
+/// # use indexing_fmt::label::*;
+/// let mut names = ["node₁₀", "node₉"];
+/// names.sort_by_key(|name| IndexedOrd(*name));
+/// assert_eq!(names, ["node₉", "node₁₀"]);
+/// ```
+///
+/// [BTreeMap]: https://doc.rust-lang.org/std/collections/struct.BTreeMap.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IndexedOrd<'a>(pub &'a str);
+
+impl PartialOrd for IndexedOrd<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for IndexedOrd<'_> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        cmp_indexed(self.0, other.0)
+    }
+}
+
+/// Counter which hands out subscripted labels and can be shared with interrupt handlers.
+///
+/// The counter is lock-free and does not allocate such that it can be placed in a `static`. After
+/// [u32::MAX] it wraps around to zero.
+///
+/// ```
+/// # use indexing_fmt::label::*;
+/// static EVENTS: AtomicLabelCounter = AtomicLabelCounter::new(1);
+///
+/// assert_eq!(format!("event{}", EVENTS.next_label()), "event₁");
+/// assert_eq!(format!("event{}", EVENTS.next_label()), "event₂");
+/// ```
+#[cfg(target_has_atomic = "32")]
+#[derive(Debug, Default)]
+pub struct AtomicLabelCounter {
+    next: core::sync::atomic::AtomicU32,
+}
+
+#[cfg(target_has_atomic = "32")]
+impl AtomicLabelCounter {
+    pub const fn new(start: u32) -> Self {
+        AtomicLabelCounter {
+            next: core::sync::atomic::AtomicU32::new(start),
+        }
+    }
+
+    /// Returns the current value and increments the counter.
+    pub fn next_label(&self) -> Subscript<u32> {
+        Subscript(
+            self.next
+                .fetch_add(1, core::sync::atomic::Ordering::Relaxed),
+        )
+    }
+
+    /// The value which will be handed out next.
+    pub fn peek(&self) -> u32 {
+        self.next.load(core::sync::atomic::Ordering::Relaxed)
+    }
+}
+
+/// Displays a positive number as traditional footnote symbol `*, †, ‡, §, ‖, ¶`.
+///
+/// After the six symbols are used up they are doubled, then tripled and so on. Zero is displayed
+/// as nothing.
+///
+/// ```
+/// # use indexing_fmt::label::*;
+/// assert_eq!(format!("x{}", FootnoteSymbol(2)), "x†");
+/// assert_eq!(format!("x{}", FootnoteSymbol(8)), "x††");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FootnoteSymbol(pub usize);
+
+impl FootnoteSymbol {
+    const SYMBOLS: [char; 6] = ['*', '\u{2020}', '\u{2021}', '\u{a7}', '\u{2016}', '\u{b6}'];
+}
+
+impl Display for FootnoteSymbol {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let Some(n) = self.0.checked_sub(1) else {
+            return Ok(());
+        };
+        let symbol = Self::SYMBOLS[n % Self::SYMBOLS.len()];
+        (0..=n / Self::SYMBOLS.len()).try_for_each(|_| f.write_char(symbol))
+    }
+}
+
+/// Stable handle of a footnote in a [FootnoteRegistry].
+///
+/// The number of a footnote changes when other footnotes are inserted or removed while its handle
+/// stays the same.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FootnoteId(usize);
+
+/// Assigns superscript markers to footnotes and renumbers them on insertion and removal.
+///
+/// This type requires the `alloc` feature.
+///
+/// ```
+/// # use indexing_fmt::label::*;
+/// let mut footnotes = FootnoteRegistry::new();
+/// let first = footnotes.push("See appendix.");
+/// let second = footnotes.push("Measured at 20°C.");
+/// let (a, b) = (footnotes.marker(first).unwrap(), footnotes.marker(second).unwrap());
+/// assert_eq!(format!("Results{a} and conditions{b}"), "Results¹ and conditions²");
+///
+/// let intro = footnotes.insert(0, "Preliminary.");
+/// assert_eq!(format!("{}", footnotes.marker(first).unwrap()), "²");
+/// footnotes.remove(intro);
+/// assert_eq!(format!("{}", footnotes.list()), "¹ See appendix.\n² Measured at 20°C.\n");
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FootnoteRegistry<T> {
+    /// The footnotes in order with the number of inline references to each.
+    notes: alloc::vec::Vec<(FootnoteId, T, usize)>,
+    next_id: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Default for FootnoteRegistry<T> {
+    fn default() -> Self {
+        FootnoteRegistry {
+            notes: alloc::vec::Vec::new(),
+            next_id: 0,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> FootnoteRegistry<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a footnote at the end.
+    pub fn push(&mut self, text: T) -> FootnoteId {
+        self.insert(self.notes.len(), text)
+    }
+
+    /// Inserts a footnote at the given zero-based position, renumbering all following ones.
+    ///
+    /// # Panics
+    /// Panics if `position > self.len()`.
+    pub fn insert(&mut self, position: usize, text: T) -> FootnoteId {
+        let id = FootnoteId(self.next_id);
+        self.next_id += 1;
+        self.notes.insert(position, (id, text, 0));
+        id
+    }
+
+    /// Removes a footnote, renumbering all following ones.
+    pub fn remove(&mut self, id: FootnoteId) -> Option<T> {
+        let position = self.notes.iter().position(|(i, _, _)| *i == id)?;
+        Some(self.notes.remove(position).1)
+    }
+
+    /// The current number of a footnote starting at one.
+    pub fn number(&self, id: FootnoteId) -> Option<usize> {
+        self.notes
+            .iter()
+            .position(|(i, _, _)| *i == id)
+            .map(|n| n + 1)
+    }
+
+    /// The inline marker referring to a footnote.
+    pub fn marker(&self, id: FootnoteId) -> Option<Superscript<usize>> {
+        self.number(id).map(Superscript)
+    }
+
+    /// The inline marker referring to a footnote as [FootnoteSymbol].
+    pub fn symbol_marker(&self, id: FootnoteId) -> Option<FootnoteSymbol> {
+        self.number(id).map(FootnoteSymbol)
+    }
+
+    /// Like [marker](FootnoteRegistry::marker), but also counts the reference, for which the
+    /// footnote gets a back-reference in [FootnoteList::back_references].
+    pub fn reference(&mut self, id: FootnoteId) -> Option<Superscript<usize>> {
+        let position = self.notes.iter().position(|(i, _, _)| *i == id)?;
+        self.notes[position].2 += 1;
+        Some(Superscript(position + 1))
+    }
+
+    /// The back-references of a footnote to its counted inline references.
+    pub fn back_references(&self, id: FootnoteId) -> Option<BackReferences> {
+        self.notes
+            .iter()
+            .find(|(i, _, _)| *i == id)
+            .map(|(_, _, references)| BackReferences(*references))
+    }
+
+    pub fn get(&self, id: FootnoteId) -> Option<&T> {
+        self.notes
+            .iter()
+            .find(|(i, _, _)| *i == id)
+            .map(|(_, text, _)| text)
+    }
+
+    pub fn len(&self) -> usize {
+        self.notes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.notes.is_empty()
+    }
+
+    /// Iterates over the footnotes in order together with their numbers.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, FootnoteId, &T)> {
+        self.notes
+            .iter()
+            .enumerate()
+            .map(|(n, (id, text, _))| (n + 1, *id, text))
+    }
+
+    /// Displays the ordered list of footnotes, one per line and each preceded by its marker.
+    pub fn list(&self) -> FootnoteList<'_, T> {
+        FootnoteList {
+            registry: self,
+            back_references: false,
+        }
+    }
+}
+
+/// See [FootnoteRegistry::list].
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug)]
+pub struct FootnoteList<'a, T> {
+    registry: &'a FootnoteRegistry<T>,
+    back_references: bool,
+}
+
+#[cfg(feature = "alloc")]
+impl<T> FootnoteList<'_, T> {
+    /// Ends every footnote with its [BackReferences].
+    ///
+    /// ```
+    /// # use indexing_fmt::label::*;
+    /// let mut footnotes = FootnoteRegistry::new();
+    /// let note = footnotes.push("See appendix.");
+    /// let (a, b) = (footnotes.reference(note).unwrap(), footnotes.reference(note).unwrap());
+    /// assert_eq!(format!("First{a} and second{b}"), "First¹ and second¹");
+    /// assert_eq!(format!("{}", footnotes.list().back_references()), "¹ See appendix. ↩ ↩²\n");
+    /// ```
+    pub fn back_references(self) -> Self {
+        FootnoteList {
+            back_references: true,
+            ..self
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Display> Display for FootnoteList<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (n, (_, text, references)) in self.registry.notes.iter().enumerate() {
+            write!(f, "{} {}", Superscript(n + 1), text)?;
+            if self.back_references && *references > 0 {
+                write!(f, " {}", BackReferences(*references))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Displays one `↩` for each inline reference to a footnote, numbered from the second on as in
+/// `↩ ↩² ↩³`.
+///
+/// The `n`-th back-reference leads back to the `n`-th marker returned by
+/// [FootnoteRegistry::reference] for the footnote.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BackReferences(pub usize);
+
+#[cfg(feature = "alloc")]
+impl Display for BackReferences {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for n in 1..=self.0 {
+            if n > 1 {
+                f.write_char(' ')?;
+            }
+            f.write_char('\u{21a9}')?;
+            if n > 1 {
+                write!(f, "{}", Superscript(n))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    extern crate std;
+
+    #[test]
+    fn indexed_order() {
+        use core::cmp::Ordering;
+        assert_eq!(cmp_indexed("x₀₁", "x₁"), Ordering::Less);
+        assert_eq!(cmp_indexed("y₁", "x₂"), Ordering::Greater);
+        assert_eq!(cmp_indexed("a²", "a₁₀"), Ordering::Less);
+        assert_eq!(
+            cmp_indexed("a⁹⁹⁹⁹⁹⁹⁹⁹⁹⁹⁹⁹⁹⁹⁹⁹⁹⁹⁹⁹⁹", "a¹⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰"),
+            Ordering::Less
+        );
+        assert_eq!(cmp_indexed("b", "b"), Ordering::Equal);
+    }
+
+    #[test]
+    fn subscript_letters() {
+        let mut tables = Numbering::new("Table").subscript();
+        tables.next_item();
+        tables.next_item();
+        assert_eq!(std::format!("{}", tables.next_sub_item()), "Table 2ₐ");
+        // There is no subscript b
+        assert_eq!(std::format!("{}", tables.next_sub_item()), "Table 2b");
+    }
+
+    #[test]
+    fn letters_roll_over() {
+        let mut numbering = Numbering::new("");
+        let labels: std::vec::Vec<_> = (0..28).map(|_| numbering.next_sub_item()).collect();
+        assert_eq!(std::format!("{}", labels[25]), "1ᶻ");
+        assert_eq!(std::format!("{}", labels[26]), "1ᵃᵃ");
+        assert_eq!(std::format!("{}", labels[27]), "1ᵃᵇ");
+        assert_eq!(labels[27].child(), Some(28));
+    }
+
+    #[test]
+    fn letter_sequence_roll_over() {
+        let labels: std::vec::Vec<_> = LetterSequence::superscript()
+            .skip(700)
+            .take(3)
+            .map(|l| std::format!("{}", l))
+            .collect();
+        assert_eq!(labels, ["ᶻʸ", "ᶻᶻ", "ᵃᵃᵃ"]);
+        assert_eq!(std::format!("{}", Letters::subscript(0)), "");
+        let res = Letters::superscript(17).unicode_version(UnicodeVersion::V14_0);
+        assert_eq!(std::format!("{}", res), "𐞥");
+        let res = Letters::superscript(3).unicode_version(UnicodeVersion::V4_0);
+        assert_eq!(std::format!("{}", res), "c");
+    }
+
+    #[test]
+    fn sub_numbers() {
+        let mut numbering = Numbering::new("Eq.").style(SubStyle::Numbers).subscript();
+        numbering.next_item();
+        numbering.next_sub_item();
+        assert_eq!(std::format!("{}", numbering.next_sub_item()), "Eq. 1₂");
+        assert_eq!(numbering.next_item().child(), None);
+    }
+
+    #[test]
+    fn collation_key_order() {
+        let mut labels = ["x₁₀", "x₂", "y", "x", "x³"];
+        labels.sort_by_key(|l| std::format!("{}", CollationKey::new(l)));
+        assert_eq!(labels, ["x", "x₂", "x³", "x₁₀", "y"]);
+    }
+
+    #[test]
+    fn atomic_counter_threads() {
+        static COUNTER: AtomicLabelCounter = AtomicLabelCounter::new(0);
+        let handles: std::vec::Vec<_> = (0..4)
+            .map(|_| std::thread::spawn(|| (0..100).map(|_| COUNTER.next_label().0).sum::<u32>()))
+            .collect();
+        let sum: u32 = handles.into_iter().map(|h| h.join().unwrap()).sum();
+        assert_eq!(sum, (0..400u32).sum::<u32>());
+        assert_eq!(COUNTER.peek(), 400);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn footnote_renumbering() {
+        let mut footnotes = FootnoteRegistry::new();
+        let a = footnotes.push("a");
+        let b = footnotes.push("b");
+        let c = footnotes.push("c");
+        assert_eq!(footnotes.remove(a), Some("a"));
+        assert_eq!(footnotes.remove(a), None);
+        assert_eq!(footnotes.number(b), Some(1));
+        assert_eq!(footnotes.number(c), Some(2));
+        assert_eq!(footnotes.marker(a), None);
+
+        let d = footnotes.insert(1, "d");
+        assert_eq!(footnotes.get(d), Some(&"d"));
+        assert_eq!(std::format!("{}", footnotes.list()), "¹ b\n² d\n³ c\n");
+        assert_eq!(footnotes.symbol_marker(c), Some(FootnoteSymbol(3)));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn footnote_back_references() {
+        let mut footnotes = FootnoteRegistry::new();
+        let a = footnotes.push("a");
+        let b = footnotes.push("b");
+        let c = footnotes.push("c");
+        assert_eq!(footnotes.reference(b), Some(Superscript(2)));
+        footnotes.reference(a);
+        footnotes.reference(b);
+        footnotes.reference(b);
+        assert_eq!(footnotes.back_references(b), Some(BackReferences(3)));
+        assert_eq!(footnotes.back_references(c), Some(BackReferences(0)));
+        footnotes.remove(a);
+        assert_eq!(footnotes.reference(a), None);
+        let res = std::format!("{}", footnotes.list().back_references());
+        assert_eq!(res, "¹ b ↩ ↩² ↩³\n² c\n");
+        assert_eq!(std::format!("{}", footnotes.list()), "¹ b\n² c\n");
+        assert_eq!(std::format!("{}", BackReferences(0)), "");
+    }
+
+    #[test]
+    fn footnote_symbols() {
+        let res: std::vec::Vec<_> = (0..=13)
+            .map(|n| std::format!("{}", FootnoteSymbol(n)))
+            .collect();
+        assert_eq!(res.concat(), "*†‡§‖¶**††‡‡§§‖‖¶¶***");
+    }
+}
@@ -16,17 +16,324 @@
 //! ```
 
 use core::fmt::Write;
+use core::sync::atomic::{AtomicBool, Ordering};
 
-const ESCAPES_SUPERSCRIPTS: [char; 10] = [
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "python")]
+extern crate std;
+
+pub mod angle;
+#[cfg(any(feature = "ethnum", feature = "primitive-types"))]
+mod bignum;
+pub mod buf;
+mod chars;
+pub mod chem;
+#[cfg(feature = "cldr")]
+pub mod cldr;
+pub mod coverage;
+mod decimal;
+pub mod expr;
+pub mod fraction;
+#[cfg(feature = "embedded-hal")]
+pub mod hal;
+mod iter;
+pub mod label;
+pub mod markup;
+pub mod math;
+pub mod ordinal;
+#[cfg(feature = "rayon")]
+pub mod par;
+pub mod parse;
+pub mod physics;
+#[cfg(feature = "uom")]
+pub mod quantity;
+pub mod radix;
+pub mod robotics;
+pub mod sci;
+pub mod scripted;
+pub mod segment;
+#[cfg(any(feature = "compact_str", feature = "smol_str"))]
+mod strings;
+pub mod styled;
+pub mod transcode;
+pub mod translit;
+pub mod truncate;
+
+#[cfg(feature = "python")]
+mod python;
+#[cfg(feature = "ux")]
+mod ux_int;
+
+pub use chars::{Fallback, Spacing, UnicodeVersion, subscript_char, superscript_char};
+pub use iter::ScriptChars;
+
+/// The superscript digits `⁰¹²³⁴⁵⁶⁷⁸⁹`, indexed by their value.
+pub const SUPERSCRIPT_DIGITS: [char; 10] = [
     '\u{2070}', '\u{00B9}', '\u{00B2}', '\u{00B3}', '\u{2074}', '\u{2075}', '\u{2076}', '\u{2077}',
     '\u{2078}', '\u{2079}',
 ];
 
-const ESCAPES_SUBSCRIPTS: [char; 10] = [
+/// The subscript digits `₀₁₂₃₄₅₆₇₈₉`, indexed by their value.
+pub const SUBSCRIPT_DIGITS: [char; 10] = [
     '\u{2080}', '\u{2081}', '\u{2082}', '\u{2083}', '\u{2084}', '\u{2085}', '\u{2086}', '\u{2087}',
     '\u{2088}', '\u{2089}',
 ];
 
+/// The superscript form of a decimal digit.
+///
+/// ```
+/// use indexing_fmt::*;
+///
+/// assert_eq!(superscript_digit(7), '⁷');
+/// ```
+///
+/// # Panics
+///
+/// If the digit is larger than 9.
+pub const fn superscript_digit(d: u8) -> char {
+    SUPERSCRIPT_DIGITS[d as usize]
+}
+
+/// The subscript form of a decimal digit.
+///
+/// ```
+/// use indexing_fmt::*;
+///
+/// assert_eq!(subscript_digit(7), '₇');
+/// ```
+///
+/// # Panics
+///
+/// If the digit is larger than 9.
+pub const fn subscript_digit(d: u8) -> char {
+    SUBSCRIPT_DIGITS[d as usize]
+}
+
+/// The value of a superscript digit, the inverse of [superscript_digit].
+///
+/// ```
+/// use indexing_fmt::*;
+///
+/// assert_eq!(from_superscript_char('³'), Some(3));
+/// assert_eq!(from_superscript_char('3'), None);
+/// ```
+pub const fn from_superscript_char(c: char) -> Option<u8> {
+    match c {
+        '\u{2070}' => Some(0),
+        '\u{00b9}' => Some(1),
+        '\u{00b2}' => Some(2),
+        '\u{00b3}' => Some(3),
+        '\u{2074}'..='\u{2079}' => Some((c as u32 - 0x2070) as u8),
+        _ => None,
+    }
+}
+
+/// The value of a subscript digit, the inverse of [subscript_digit].
+///
+/// ```
+/// use indexing_fmt::*;
+///
+/// assert_eq!(from_subscript_char('₉'), Some(9));
+/// assert_eq!(from_subscript_char('⁹'), None);
+/// ```
+pub const fn from_subscript_char(c: char) -> Option<u8> {
+    match c {
+        '\u{2080}'..='\u{2089}' => Some((c as u32 - 0x2080) as u8),
+        _ => None,
+    }
+}
+
+static PLAIN_MODE: AtomicBool = AtomicBool::new(false);
+
+/// Makes the integer conversions and character mappings write plain ASCII digits, signs and
+/// letters instead of script characters.
+///
+/// This is meant for golden-file tests and diffs which should not depend on script glyphs. The
+/// switch is global and read by every subsequent conversion, so call sites do not change. Tests
+/// which run in parallel within one process all observe the same setting.
+///
+/// ```
+/// use indexing_fmt::*;
+///
+/// set_plain_mode(true);
+/// assert_eq!(format!("x{}", (-12).to_superscript()), "x-12");
+/// set_plain_mode(false);
+/// assert_eq!(format!("x{}", (-12).to_superscript()), "x⁻¹²");
+/// ```
+pub fn set_plain_mode(enabled: bool) {
+    PLAIN_MODE.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether [set_plain_mode] is enabled.
+pub fn plain_mode() -> bool {
+    PLAIN_MODE.load(Ordering::Relaxed)
+}
+
+/// Vertical placement of a script relative to the baseline.
+///
+/// This allows to choose the position at runtime with [FormatScript::to_script], or at compile time
+/// with [ScriptForm].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ScriptPosition {
+    /// Raised above the baseline like `x²`.
+    Superscript,
+    /// Lowered below the baseline like `x₂`.
+    Subscript,
+}
+
+/// An integer which is written with script digits.
+///
+/// The width, fill, alignment and `+` flag of the formatter are honored like for plain integers,
+/// which are aligned to the right by default. The alternate flag `{:#}` writes ASCII such as `^12`
+/// and `_12` for targets which cannot display scripts. Zero-padding such as `{:03}` inserts script
+/// zeros between the sign and the digits.
+pub(crate) struct ScriptInteger {
+    pub(crate) negative: bool,
+    pub(crate) magnitude: u128,
+    pub(crate) position: ScriptPosition,
+    pub(crate) radix: u32,
+    pub(crate) uppercase: bool,
+    /// Determines how letter digits without script form are written.
+    pub(crate) fallback: Fallback,
+    /// The size of digit groups and the separator written between them.
+    pub(crate) grouping: Option<(usize, char)>,
+}
+
+impl ScriptInteger {
+    pub(crate) fn decimal(negative: bool, magnitude: u128, position: ScriptPosition) -> Self {
+        ScriptInteger {
+            negative,
+            magnitude,
+            position,
+            radix: 10,
+            uppercase: false,
+            fallback: Fallback::Error,
+            grouping: None,
+        }
+    }
+
+    pub(crate) fn write(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        const ASCII_DIGITS: [char; 10] = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
+        let marker = match self.position {
+            _ if !f.alternate() || plain_mode() => None,
+            ScriptPosition::Superscript => Some('^'),
+            ScriptPosition::Subscript => Some('_'),
+        };
+        let ascii = plain_mode() || marker.is_some();
+        let (digits, minus, plus) = match self.position {
+            _ if ascii => (ASCII_DIGITS, '-', '+'),
+            ScriptPosition::Superscript => (SUPERSCRIPT_DIGITS, '\u{207b}', '\u{207a}'),
+            ScriptPosition::Subscript => (SUBSCRIPT_DIGITS, '\u{208b}', '\u{208a}'),
+        };
+        let sign = match self.negative {
+            true => Some(minus),
+            false => Some(plus).filter(|_| f.sign_plus()),
+        };
+        let count = digit_count(self.magnitude, self.radix);
+        let separators = match self.grouping {
+            Some((size, _)) if size > 0 => (count as usize - 1) / size,
+            _ => 0,
+        };
+        let len = count as usize
+            + separators
+            + usize::from(sign.is_some())
+            + usize::from(marker.is_some());
+        let padding = f.width().unwrap_or(0).saturating_sub(len);
+        let (zeros, padding) = match f.sign_aware_zero_pad() {
+            true => (padding, 0),
+            false => (0, padding),
+        };
+        let (before, after) = match f.align() {
+            Some(core::fmt::Alignment::Left) => (0, padding),
+            Some(core::fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+            _ => (padding, 0),
+        };
+        let fill = f.fill();
+        (0..before).try_for_each(|_| f.write_char(fill))?;
+        if let Some(marker) = marker {
+            f.write_char(marker)?;
+        }
+        if let Some(sign) = sign {
+            f.write_char(sign)?;
+        }
+        (0..zeros).try_for_each(|_| f.write_char(digits[0]))?;
+        for power in (0..count).rev() {
+            let digit =
+                (self.magnitude / (self.radix as u128).pow(power) % self.radix as u128) as u8;
+            if digit < 10 {
+                f.write_char(digits[digit as usize])?;
+            } else {
+                let letter = match self.uppercase {
+                    true => (b'A' + digit - 10) as char,
+                    false => (b'a' + digit - 10) as char,
+                };
+                match chars::script_char(letter, self.position, UnicodeVersion::V6_0) {
+                    _ if ascii => f.write_char(letter)?,
+                    Some(s) => f.write_char(s)?,
+                    None => self.fallback.write(f, letter)?,
+                }
+            }
+            match self.grouping {
+                Some((size, separator)) if power > 0 && size > 0 && power as usize % size == 0 => {
+                    f.write_char(separator)?
+                }
+                _ => (),
+            }
+        }
+        (0..after).try_for_each(|_| f.write_char(fill))
+    }
+}
+
+/// The number of digits of `magnitude` in the given radix, which is one for zero.
+///
+/// Counting by division instead of with `ilog` keeps the crate free of integer logarithms, which
+/// older toolchains lack.
+pub(crate) fn digit_count(mut magnitude: u128, radix: u32) -> u32 {
+    let mut count = 1;
+    while magnitude >= radix as u128 {
+        magnitude /= radix as u128;
+        count += 1;
+    }
+    count
+}
+
+/// Counts the characters written to it.
+struct CharCount(usize);
+
+impl Write for CharCount {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// Writes `body` padded to the width of the formatter with its fill and alignment.
+///
+/// The output is written twice when a width is given, once to count its characters. The `body`
+/// should therefore not depend on the flags of `f`, which are not seen by the counting pass.
+pub(crate) fn pad(
+    f: &mut core::fmt::Formatter<'_>,
+    default: core::fmt::Alignment,
+    body: impl Fn(&mut dyn Write) -> core::fmt::Result,
+) -> core::fmt::Result {
+    let Some(width) = f.width() else {
+        return body(f);
+    };
+    let mut count = CharCount(0);
+    body(&mut count)?;
+    let padding = width.saturating_sub(count.0);
+    let (before, after) = match f.align().unwrap_or(default) {
+        core::fmt::Alignment::Left => (0, padding),
+        core::fmt::Alignment::Center => (padding / 2, padding - padding / 2),
+        core::fmt::Alignment::Right => (padding, 0),
+    };
+    let fill = f.fill();
+    (0..before).try_for_each(|_| f.write_char(fill))?;
+    body(f)?;
+    (0..after).try_for_each(|_| f.write_char(fill))
+}
+
 /// This type should probably not be used directly.
 ///
 /// See the [crate] level documentation and [FormatSuperscript::to_superscript].
@@ -43,35 +350,36 @@ where
     Self: Sized,
 {
     fn to_superscript(&self) -> Superscript<Self>;
+
+    /// Formats the value as superscript into a new [String](alloc::string::String).
+    ///
+    /// This method requires the `alloc` feature.
+    ///
+    /// ```
+    /// # use indexing_fmt::*;
+    /// assert_eq!(12.to_superscript_string(), "¹²");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn to_superscript_string(&self) -> alloc::string::String
+    where
+        Superscript<Self>: core::fmt::Display,
+    {
+        alloc::string::ToString::to_string(&self.to_superscript())
+    }
 }
 
 macro_rules! impl_superscript(
     ($ty_unsigned:ty, $ty_signed:ty) => {
         impl core::fmt::Display for Superscript<$ty_unsigned> {
             fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                if self.0 == 0 {
-                    f.write_char(ESCAPES_SUPERSCRIPTS[0])?;
-                } else {
-                    let mut value = self.0;
-                    let max_base = value.ilog10();
-                    for base in (0..max_base + 1).rev() {
-                        let b = (10 as $ty_unsigned).pow(base);
-                        let digit = value / b;
-                        f.write_char(ESCAPES_SUPERSCRIPTS[digit as usize])?;
-                        value %= b;
-                    }
-                }
-                Ok(())
+                ScriptInteger::decimal(false, self.0 as u128, ScriptPosition::Superscript).write(f)
             }
         }
 
         impl core::fmt::Display for Superscript<$ty_signed> {
             fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                if self.0 < 0 {
-                    f.write_char('\u{207b}')?;
-                }
-                let new_value = Superscript(self.0.unsigned_abs());
-                <Superscript<$ty_unsigned> as core::fmt::Display>::fmt(&new_value, f)
+                let magnitude = self.0.unsigned_abs() as u128;
+                ScriptInteger::decimal(self.0 < 0, magnitude, ScriptPosition::Superscript).write(f)
             }
         }
 
@@ -89,6 +397,7 @@ macro_rules! impl_superscript(
     };
 );
 
+impl_superscript!(u128, i128);
 impl_superscript!(usize, isize);
 impl_superscript!(u64, i64);
 impl_superscript!(u32, i32);
@@ -111,36 +420,36 @@ where
     Self: Sized,
 {
     fn to_subscript(&self) -> Subscript<Self>;
+
+    /// Formats the value as subscript into a new [String](alloc::string::String).
+    ///
+    /// This method requires the `alloc` feature.
+    ///
+    /// ```
+    /// # use indexing_fmt::*;
+    /// assert_eq!((-840).to_subscript_string(), "₋₈₄₀");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn to_subscript_string(&self) -> alloc::string::String
+    where
+        Subscript<Self>: core::fmt::Display,
+    {
+        alloc::string::ToString::to_string(&self.to_subscript())
+    }
 }
 
 macro_rules! impl_subscript(
     ($ty_unsigned:ty, $ty_signed:ty) => {
         impl core::fmt::Display for Subscript<$ty_unsigned> {
             fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                // If zero, insert only one entry
-                if self.0 == 0 {
-                    f.write_char(ESCAPES_SUBSCRIPTS[0])?;
-                } else {
-                    let mut value = self.0;
-                    let max_base = value.ilog10();
-                    for base in (0..max_base + 1).rev() {
-                        let b = (10 as $ty_unsigned).pow(base);
-                        let digit = value / b;
-                        f.write_char(ESCAPES_SUBSCRIPTS[digit as usize])?;
-                        value %= b;
-                    }
-                }
-                Ok(())
+                ScriptInteger::decimal(false, self.0 as u128, ScriptPosition::Subscript).write(f)
             }
         }
 
         impl core::fmt::Display for Subscript<$ty_signed> {
             fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                if self.0 < 0 {
-                    f.write_char('\u{208b}')?;
-                }
-                let new_value = Subscript(self.0.unsigned_abs());
-                <Subscript<$ty_unsigned> as core::fmt::Display>::fmt(&new_value, f)
+                let magnitude = self.0.unsigned_abs() as u128;
+                ScriptInteger::decimal(self.0 < 0, magnitude, ScriptPosition::Subscript).write(f)
             }
         }
 
@@ -158,12 +467,401 @@ macro_rules! impl_subscript(
     };
 );
 
+impl_subscript!(u128, i128);
 impl_subscript!(usize, isize);
 impl_subscript!(u64, i64);
 impl_subscript!(u32, i32);
 impl_subscript!(u16, i16);
 impl_subscript!(u8, i8);
 
+/// A value formatted as superscript or subscript depending on a [ScriptPosition].
+///
+/// See [FormatScript::to_script].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Script<T> {
+    Superscript(Superscript<T>),
+    Subscript(Subscript<T>),
+}
+
+impl<T> core::fmt::Display for Script<T>
+where
+    Superscript<T>: core::fmt::Display,
+    Subscript<T>: core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Script::Superscript(value) => core::fmt::Display::fmt(value, f),
+            Script::Subscript(value) => core::fmt::Display::fmt(value, f),
+        }
+    }
+}
+
+/// Converts to superscripts or subscripts with the position chosen at runtime.
+///
+/// This trait is implemented for every type which implements both [FormatSuperscript] and
+/// [FormatSubscript].
+///
+/// ```
+/// use indexing_fmt::*;
+///
+/// let position = ScriptPosition::Subscript;
+/// assert_eq!(format!("x{}", 12.to_script(position)), "x₁₂");
+/// assert_eq!(format!("x{}", 12.to_script(ScriptPosition::Superscript)), "x¹²");
+/// ```
+pub trait FormatScript: FormatSuperscript + FormatSubscript {
+    fn to_script(&self, position: ScriptPosition) -> Script<Self> {
+        match position {
+            ScriptPosition::Superscript => Script::Superscript(self.to_superscript()),
+            ScriptPosition::Subscript => Script::Subscript(self.to_subscript()),
+        }
+    }
+}
+
+impl<T: FormatSuperscript + FormatSubscript> FormatScript for T {}
+
+/// Common interface of [Superscript] and [Subscript] for code which is generic over the position.
+///
+/// The position is then chosen at compile time by the wrapper type, while [FormatScript::to_script]
+/// chooses it at runtime.
+///
+/// ```
+/// use core::fmt::Display;
+/// use indexing_fmt::*;
+///
+/// fn indexed<S: ScriptForm<u32> + Display>(name: &str, index: u32) -> String {
+///     format!("{}{}", name, S::new(index))
+/// }
+///
+/// assert_eq!(indexed::<Superscript<_>>("x", 2), "x²");
+/// assert_eq!(indexed::<Subscript<_>>("x", 2), "x₂");
+/// assert_eq!(Subscript::<u8>::POSITION, ScriptPosition::Subscript);
+/// ```
+pub trait ScriptForm<T> {
+    /// The position at which the wrapped value is written.
+    const POSITION: ScriptPosition;
+
+    /// Wraps the value.
+    fn new(value: T) -> Self;
+
+    /// Unwraps the value.
+    fn into_inner(self) -> T;
+}
+
+impl<T> ScriptForm<T> for Superscript<T> {
+    const POSITION: ScriptPosition = ScriptPosition::Superscript;
+
+    fn new(value: T) -> Self {
+        Superscript(value)
+    }
+
+    fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> ScriptForm<T> for Subscript<T> {
+    const POSITION: ScriptPosition = ScriptPosition::Subscript;
+
+    fn new(value: T) -> Self {
+        Subscript(value)
+    }
+
+    fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+macro_rules! impl_radix_traits(
+    ($wrapper:ident, $position:expr, $($ty_unsigned:ty, $ty_signed:ty);* $(;)?) => {
+        $(
+            impl_radix_traits!(@trait $wrapper, $position, $ty_unsigned, $ty_unsigned);
+            impl_radix_traits!(@trait $wrapper, $position, $ty_signed, $ty_unsigned);
+        )*
+    };
+    (@trait $wrapper:ident, $position:expr, $ty:ty, $ty_unsigned:ty) => {
+        impl_radix_traits!(@impl Binary, $wrapper, $position, $ty, $ty_unsigned, 2, false);
+        impl_radix_traits!(@impl Octal, $wrapper, $position, $ty, $ty_unsigned, 8, false);
+        impl_radix_traits!(@impl LowerHex, $wrapper, $position, $ty, $ty_unsigned, 16, false);
+        impl_radix_traits!(@impl UpperHex, $wrapper, $position, $ty, $ty_unsigned, 16, true);
+    };
+    (@impl $trait:ident, $wrapper:ident, $position:expr, $ty:ty, $ty_unsigned:ty, $radix:expr, $uppercase:expr) => {
+        /// Negative values are written in two's complement like for plain integers. Letter digits
+        /// without script form make formatting fail with [core::fmt::Error]. Like for [Display],
+        /// the alternate flag writes ASCII instead of adding a prefix such as `0x`.
+        ///
+        /// [Display]: core::fmt::Display
+        impl core::fmt::$trait for $wrapper<$ty> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                ScriptInteger {
+                    radix: $radix,
+                    uppercase: $uppercase,
+                    ..ScriptInteger::decimal(false, self.0 as $ty_unsigned as u128, $position)
+                }
+                .write(f)
+            }
+        }
+    };
+);
+
+impl_radix_traits!(
+    Superscript, ScriptPosition::Superscript,
+    u128, i128; usize, isize; u64, i64; u32, i32; u16, i16; u8, i8;
+);
+impl_radix_traits!(
+    Subscript, ScriptPosition::Subscript,
+    u128, i128; usize, isize; u64, i64; u32, i32; u16, i16; u8, i8;
+);
+
+macro_rules! impl_non_zero(
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl core::fmt::Display for Superscript<core::num::NonZero<$ty>> {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    core::fmt::Display::fmt(&Superscript(self.0.get()), f)
+                }
+            }
+
+            impl core::fmt::Display for Subscript<core::num::NonZero<$ty>> {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    core::fmt::Display::fmt(&Subscript(self.0.get()), f)
+                }
+            }
+
+            impl FormatSuperscript for core::num::NonZero<$ty> {
+                fn to_superscript(&self) -> Superscript<Self> {
+                    Superscript(*self)
+                }
+            }
+
+            impl FormatSubscript for core::num::NonZero<$ty> {
+                fn to_subscript(&self) -> Subscript<Self> {
+                    Subscript(*self)
+                }
+            }
+        )*
+    };
+);
+
+impl_non_zero!(
+    u128, i128, usize, isize, u64, i64, u32, i32, u16, i16, u8, i8
+);
+
+/// Like [chars::superscript_char], but writes the decimal point as U+00B7 MIDDLE DOT.
+fn superscript_float_char(c: char) -> Option<char> {
+    match c {
+        '.' => Some('\u{b7}'),
+        c => chars::superscript_char(c),
+    }
+}
+
+/// Writes a float with the precision of the formatter, or with as many digits as needed otherwise.
+fn write_float(
+    w: &mut impl Write,
+    value: impl core::fmt::Display,
+    precision: Option<usize>,
+) -> core::fmt::Result {
+    match precision {
+        Some(precision) => write!(w, "{:.*}", precision, value),
+        None => write!(w, "{}", value),
+    }
+}
+
+macro_rules! impl_float(
+    ($($ty:ty),* $(,)?) => {
+        $(
+            /// Unicode has no superscript full stop, so the decimal point is written as the raised
+            /// `·` U+00B7 MIDDLE DOT, as in `x⁻¹·⁵`. Infinities are written as `∞` and NaN as `ᴺᵃᴺ`.
+            /// The precision, width, fill and alignment of the formatter are honored like for plain
+            /// floats, which are aligned to the right by default.
+            impl core::fmt::Display for Superscript<$ty> {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    let (value, precision) = (self.0, f.precision());
+                    pad(f, core::fmt::Alignment::Right, |mut w| {
+                        if plain_mode() {
+                            return write_float(&mut w, value, precision);
+                        }
+                        if value.is_nan() {
+                            return w.write_str("\u{1d3a}\u{1d43}\u{1d3a}");
+                        }
+                        if value.is_infinite() {
+                            return w.write_str(if value < 0.0 { "\u{207b}\u{221e}" } else { "\u{221e}" });
+                        }
+                        let mut mapped = chars::Mapped {
+                            inner: &mut w,
+                            map: superscript_float_char,
+                            fallback: Fallback::Keep,
+                        };
+                        write_float(&mut mapped, value, precision)
+                    })
+                }
+            }
+
+            /// The decimal point is kept as `.`, which already sits at the height of subscripts, as
+            /// in `x₁.₅`. Infinities are written as `∞` and NaN as `NaN`, since there is no subscript
+            /// `N`.
+            impl core::fmt::Display for Subscript<$ty> {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    let (value, precision) = (self.0, f.precision());
+                    pad(f, core::fmt::Alignment::Right, |mut w| {
+                        if plain_mode() || value.is_nan() {
+                            return write_float(&mut w, value, precision);
+                        }
+                        if value.is_infinite() {
+                            return w.write_str(if value < 0.0 { "\u{208b}\u{221e}" } else { "\u{221e}" });
+                        }
+                        let mut mapped = chars::Mapped {
+                            inner: &mut w,
+                            map: chars::subscript_char,
+                            fallback: Fallback::Keep,
+                        };
+                        write_float(&mut mapped, value, precision)
+                    })
+                }
+            }
+
+            impl FormatSuperscript for $ty {
+                fn to_superscript(&self) -> Superscript<Self> {
+                    Superscript(*self)
+                }
+            }
+
+            impl FormatSubscript for $ty {
+                fn to_subscript(&self) -> Subscript<Self> {
+                    Subscript(*self)
+                }
+            }
+        )*
+    };
+);
+
+impl_float!(f64, f32);
+
+/// Letters without superscript form, such as `q`, the capitals `C F Q S X Y Z` and most Greek, are
+/// written unchanged, as are spaces and other symbols. The `to_superscript_strict` method of
+/// `translit::TranslitStr` rejects them instead.
+///
+/// ```
+/// use indexing_fmt::*;
+///
+/// assert_eq!(format!("x{}", 'n'.to_superscript()), "xⁿ");
+/// assert_eq!(format!("{}", 'q'.to_superscript()), "q");
+/// ```
+impl core::fmt::Display for Superscript<char> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let c = chars::script_char(self.0, ScriptPosition::Superscript, UnicodeVersion::V6_0);
+        f.write_char(c.unwrap_or(self.0))
+    }
+}
+
+/// Every character is converted on its own, keeping those without superscript form.
+///
+/// ```
+/// use indexing_fmt::*;
+///
+/// assert_eq!(format!("x{}", "n+k".to_superscript()), "xⁿ⁺ᵏ");
+/// assert_eq!(format!("{}", "kg·m".to_superscript()), "ᵏᵍ·ᵐ");
+/// ```
+impl core::fmt::Display for Superscript<&str> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0
+            .chars()
+            .try_for_each(|c| core::fmt::Display::fmt(&Superscript(c), f))
+    }
+}
+
+impl FormatSuperscript for char {
+    fn to_superscript(&self) -> Superscript<Self> {
+        Superscript(*self)
+    }
+}
+
+impl FormatSuperscript for &str {
+    fn to_superscript(&self) -> Superscript<Self> {
+        Superscript(self)
+    }
+}
+
+impl Superscript<&str> {
+    /// Checks that every character has a superscript form, returning the first which has not.
+    ///
+    /// ```
+    /// use indexing_fmt::*;
+    /// use indexing_fmt::translit::Unmappable;
+    ///
+    /// assert_eq!(format!("{}", "n+k".to_superscript().strict()?), "ⁿ⁺ᵏ");
+    /// assert_eq!("n+q".to_superscript().strict(), Err(Unmappable('q')));
+    /// # Ok::<(), Unmappable>(())
+    /// ```
+    pub fn strict(self) -> Result<Self, translit::Unmappable> {
+        strict(self.0, ScriptPosition::Superscript).map(Superscript)
+    }
+}
+
+/// Only the letters `a e h i j k l m n o p r s t u v x` have a subscript form, all other letters
+/// are written unchanged like spaces and other symbols. The `strict` method of `Subscript<&str>`
+/// finds them before formatting.
+///
+/// ```
+/// use indexing_fmt::*;
+///
+/// assert_eq!(format!("T{}", 'x'.to_subscript()), "Tₓ");
+/// assert_eq!(format!("{}", 'b'.to_subscript()), "b");
+/// ```
+impl core::fmt::Display for Subscript<char> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let c = chars::script_char(self.0, ScriptPosition::Subscript, UnicodeVersion::V6_0);
+        f.write_char(c.unwrap_or(self.0))
+    }
+}
+
+/// Every character is converted on its own, keeping those without subscript form.
+impl core::fmt::Display for Subscript<&str> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0
+            .chars()
+            .try_for_each(|c| core::fmt::Display::fmt(&Subscript(c), f))
+    }
+}
+
+impl FormatSubscript for char {
+    fn to_subscript(&self) -> Subscript<Self> {
+        Subscript(*self)
+    }
+}
+
+impl FormatSubscript for &str {
+    fn to_subscript(&self) -> Subscript<Self> {
+        Subscript(self)
+    }
+}
+
+impl Subscript<&str> {
+    /// Checks that every character has a subscript form, returning the first which has not.
+    ///
+    /// ```
+    /// use indexing_fmt::*;
+    /// use indexing_fmt::translit::Unmappable;
+    ///
+    /// assert_eq!(format!("R{}", "ijkl".to_subscript().strict()?), "Rᵢⱼₖₗ");
+    /// assert_eq!("max".to_subscript().strict(), Ok(Subscript("max")));
+    /// assert_eq!("min y".to_subscript().strict(), Err(Unmappable(' ')));
+    /// # Ok::<(), Unmappable>(())
+    /// ```
+    pub fn strict(self) -> Result<Self, translit::Unmappable> {
+        strict(self.0, ScriptPosition::Subscript).map(Subscript)
+    }
+}
+
+fn strict(text: &str, position: ScriptPosition) -> Result<&str, translit::Unmappable> {
+    match text
+        .chars()
+        .find(|c| chars::script_char(*c, position, UnicodeVersion::V6_0).is_none())
+    {
+        Some(c) => Err(translit::Unmappable(c)),
+        None => Ok(text),
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -219,4 +917,140 @@ mod test {
         let res = std::format!("gh{}", 15670.to_subscript());
         assert_eq!(res, "gh₁₅₆₇₀");
     }
+
+    #[test]
+    fn width_fill_and_alignment() {
+        assert_eq!(std::format!("{:>6}", 5.to_subscript()), "     ₅");
+        assert_eq!(std::format!("{:4}", (-12).to_superscript()), " ⁻¹²");
+        assert_eq!(std::format!("{:*<4}|", 12u8.to_subscript()), "₁₂**|");
+        assert_eq!(std::format!("{:^5}", 7.to_superscript()), "  ⁷  ");
+        assert_eq!(std::format!("{:1}", 123.to_superscript()), "¹²³");
+    }
+
+    #[test]
+    fn sign_plus() {
+        assert_eq!(std::format!("{:+}", 3.to_superscript()), "⁺³");
+        assert_eq!(std::format!("{:+}", 0u8.to_subscript()), "₊₀");
+        assert_eq!(std::format!("{:+}", (-3).to_subscript()), "₋₃");
+        assert_eq!(std::format!("{:+3}", 3.to_superscript()), " ⁺³");
+    }
+
+    #[test]
+    fn alternate_ascii() {
+        assert_eq!(std::format!("x{:#}", 12.to_superscript()), "x^12");
+        assert_eq!(std::format!("x{:#}", (-3).to_subscript()), "x_-3");
+        assert_eq!(std::format!("x{:+#4}", 3u64.to_superscript()), "x ^+3");
+    }
+
+    #[test]
+    fn zero_padding() {
+        assert_eq!(std::format!("Ship{:03}", 7.to_superscript()), "Ship⁰⁰⁷");
+        assert_eq!(std::format!("{:04}", (-7).to_subscript()), "₋₀₀₇");
+        assert_eq!(std::format!("{:<+04}", 7.to_superscript()), "⁺⁰⁰⁷");
+        assert_eq!(std::format!("{:#03}", 7.to_subscript()), "_07");
+        assert_eq!(std::format!("{:02}", 123.to_subscript()), "₁₂₃");
+    }
+
+    #[test]
+    fn radix_traits() {
+        assert_eq!(std::format!("{:b}", 5.to_superscript()), "¹⁰¹");
+        assert_eq!(std::format!("{:o}", 64u8.to_subscript()), "₁₀₀");
+        assert_eq!(std::format!("{:x}", 0xbeefu16.to_superscript()), "ᵇᵉᵉᶠ");
+        assert_eq!(std::format!("{:X}", 0xabu8.to_superscript()), "ᴬᴮ");
+        assert_eq!(std::format!("{:b}", (-1i8).to_subscript()), "₁₁₁₁₁₁₁₁");
+        assert_eq!(std::format!("{:#x}", 0xcu8.to_subscript()), "_c");
+        let mut res = std::string::String::new();
+        let error = core::fmt::write(&mut res, format_args!("{:x}", 0xcu8.to_subscript()));
+        assert!(error.is_err());
+    }
+
+    #[test]
+    fn non_zero() {
+        let index = core::num::NonZeroUsize::new(3).unwrap();
+        assert_eq!(std::format!("x{}", index.to_subscript()), "x₃");
+        let exponent = core::num::NonZeroI32::new(-12).unwrap();
+        assert_eq!(std::format!("x{}", exponent.to_superscript()), "x⁻¹²");
+    }
+
+    #[test]
+    fn floats() {
+        assert_eq!(std::format!("x{}", (-1.5).to_superscript()), "x⁻¹·⁵");
+        assert_eq!(std::format!("x{}", 0.25f32.to_subscript()), "x₀.₂₅");
+        assert_eq!(std::format!("{}", f64::NEG_INFINITY.to_superscript()), "⁻∞");
+        assert_eq!(std::format!("{}", f32::INFINITY.to_subscript()), "∞");
+        assert_eq!(std::format!("{}", f64::NAN.to_superscript()), "ᴺᵃᴺ");
+        assert_eq!(std::format!("{}", f64::NAN.to_subscript()), "NaN");
+    }
+
+    #[test]
+    fn digit_counts() {
+        assert_eq!(digit_count(0, 10), 1);
+        assert_eq!(digit_count(9, 10), 1);
+        assert_eq!(digit_count(10, 10), 2);
+        assert_eq!(digit_count(u128::MAX, 10), 39);
+        assert_eq!(digit_count(u128::MAX, 2), 128);
+        assert_eq!(digit_count(35, 36), 1);
+    }
+
+    #[test]
+    fn float_precision() {
+        assert_eq!(std::format!("{:.2}", Superscript(1.23456)), "¹·²³");
+        assert_eq!(std::format!("{:.0}", Subscript(-2.5f32)), "₋₂");
+        assert_eq!(std::format!("{:.3}", Subscript(0.5)), "₀.₅₀₀");
+    }
+
+    #[test]
+    fn float_padding() {
+        assert_eq!(std::format!("{:>8}", 1.5.to_superscript()), "     ¹·⁵");
+        assert_eq!(std::format!("{:8}", 1.5.to_superscript()), "     ¹·⁵");
+        assert_eq!(std::format!("{:-<6.1}|", Subscript(2.25)), "₂.₂---|");
+        assert_eq!(
+            std::format!("{:^5}|", f64::INFINITY.to_superscript()),
+            "  ∞  |"
+        );
+    }
+
+    #[test]
+    fn superscript_letters() {
+        let res = std::format!("{}", "abcdefghijklmnoprstuvwxyz".to_superscript());
+        assert_eq!(res, "ᵃᵇᶜᵈᵉᶠᵍʰⁱʲᵏˡᵐⁿᵒᵖʳˢᵗᵘᵛʷˣʸᶻ");
+        assert_eq!(std::format!("{}", "Cq(T)".to_superscript()), "Cq⁽ᵀ⁾");
+    }
+
+    #[test]
+    fn subscript_letters() {
+        let res = std::format!("{}", "aehijklmnoprstuvx".to_subscript().strict().unwrap());
+        assert_eq!(res, "ₐₑₕᵢⱼₖₗₘₙₒₚᵣₛₜᵤᵥₓ");
+        assert!("bcdfgqwyz".chars().all(|c| subscript_char(c).is_none()));
+        assert_eq!("".to_subscript().strict(), Ok(Subscript("")));
+    }
+
+    #[test]
+    fn greek_letters() {
+        assert_eq!(std::format!("{}", "βγδθφχ".to_superscript()), "ᵝᵞᵟᶿᵠᵡ");
+        let res = std::format!("{}", "βγρφχ".to_subscript().strict().unwrap());
+        assert_eq!(res, "ᵦᵧᵨᵩᵪ");
+        assert_eq!("α".to_subscript().strict(), Err(translit::Unmappable('α')));
+    }
+
+    #[test]
+    fn runtime_position() {
+        let positions = [ScriptPosition::Superscript, ScriptPosition::Subscript];
+        let res = positions.map(|p| std::format!("{}{}", (-3i8).to_script(p), "n".to_script(p)));
+        assert_eq!(res, ["⁻³ⁿ", "₋₃ₙ"]);
+        assert_eq!(
+            2.5.to_script(ScriptPosition::Subscript),
+            Script::Subscript(Subscript(2.5))
+        );
+    }
+
+    #[test]
+    fn beyond_u64() {
+        let res = std::format!("{}", (u64::MAX as u128 + 1).to_superscript());
+        assert_eq!(res, "¹⁸⁴⁴⁶⁷⁴⁴⁰⁷³⁷⁰⁹⁵⁵¹⁶¹⁶");
+        let res = std::format!("{}", u128::MAX.to_subscript());
+        assert_eq!(res, "₃₄₀₂₈₂₃₆₆₉₂₀₉₃₈₄₆₃₄₆₃₃₇₄₆₀₇₄₃₁₇₆₈₂₁₁₄₅₅");
+        let res = std::format!("{}", i128::MIN.to_superscript());
+        assert_eq!(res, "⁻¹⁷⁰¹⁴¹¹⁸³⁴⁶⁰⁴⁶⁹²³¹⁷³¹⁶⁸⁷³⁰³⁷¹⁵⁸⁸⁴¹⁰⁵⁷²⁸");
+    }
 }
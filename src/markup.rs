@@ -0,0 +1,232 @@
+//! Output of superscripts and subscripts as markup instead of Unicode characters.
+//!
+//! ```
+//! use indexing_fmt::*;
+//! use indexing_fmt::markup::Markup;
+//!
+//! let res = format!("x{}", 12.to_superscript().markup(Markup::Pandoc));
+//! assert_eq!(res, "x^12^");
+//!
+//! let res = format!("x{}", 3.to_subscript().markup(Markup::Html));
+//! assert_eq!(res, "x<sub>3</sub>");
+//! ```
+
+use core::fmt::{Display, Write};
+
+use crate::{ScriptPosition, Subscript, Superscript};
+
+pub mod html;
+#[cfg(feature = "ooxml")]
+pub mod ooxml;
+pub mod ssml;
+pub mod svg;
+
+/// Markup languages in which scripts can be written.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Markup {
+    /// Pandoc flavored Markdown, i.e. `^12^` and `~3~`.
+    Pandoc,
+    /// HTML tags, i.e. `<sup>12</sup>` and `<sub>3</sub>`, which are also understood by most
+    /// Markdown processors.
+    Html,
+}
+
+/// Displays the value of a script using [Markup].
+///
+/// This type is obtained by [Superscript::markup] and [Subscript::markup].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct WithMarkup<T> {
+    value: T,
+    position: ScriptPosition,
+    markup: Markup,
+}
+
+impl<T> Superscript<T> {
+    /// See the [module](crate::markup) level documentation.
+    pub fn markup(self, markup: Markup) -> WithMarkup<T> {
+        WithMarkup {
+            value: self.0,
+            position: ScriptPosition::Superscript,
+            markup,
+        }
+    }
+}
+
+impl<T> Subscript<T> {
+    /// See the [module](crate::markup) level documentation.
+    pub fn markup(self, markup: Markup) -> WithMarkup<T> {
+        WithMarkup {
+            value: self.0,
+            position: ScriptPosition::Subscript,
+            markup,
+        }
+    }
+}
+
+/// Escapes characters with special meaning in the markup language.
+struct Escape<'a, W> {
+    inner: &'a mut W,
+    markup: Markup,
+}
+
+impl<W: Write> Write for Escape<'_, W> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        s.chars().try_for_each(|c| self.write_char(c))
+    }
+
+    fn write_char(&mut self, c: char) -> core::fmt::Result {
+        match (self.markup, c) {
+            (Markup::Pandoc, ' ' | '^' | '~' | '\\') => {
+                self.inner.write_char('\\')?;
+                self.inner.write_char(c)
+            }
+            (Markup::Html, '&') => self.inner.write_str("&amp;"),
+            (Markup::Html, '<') => self.inner.write_str("&lt;"),
+            (Markup::Html, '>') => self.inner.write_str("&gt;"),
+            (Markup::Html, '"') => self.inner.write_str("&quot;"),
+            _ => self.inner.write_char(c),
+        }
+    }
+}
+
+impl<T: Display> Display for WithMarkup<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let (open, close) = match (self.markup, self.position) {
+            (Markup::Pandoc, ScriptPosition::Superscript) => ("^", "^"),
+            (Markup::Pandoc, ScriptPosition::Subscript) => ("~", "~"),
+            (Markup::Html, ScriptPosition::Superscript) => ("<sup>", "</sup>"),
+            (Markup::Html, ScriptPosition::Subscript) => ("<sub>", "</sub>"),
+        };
+        f.write_str(open)?;
+        write!(
+            Escape {
+                inner: f,
+                markup: self.markup,
+            },
+            "{}",
+            self.value
+        )?;
+        f.write_str(close)
+    }
+}
+
+/// Syntax of escape sequences for non-ASCII characters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EscapeStyle {
+    /// `\u{00B2}` as used in Rust string literals.
+    Rust,
+    /// `\u00B2` as used in JSON and many other languages. Characters outside of the Basic
+    /// Multilingual Plane are written as surrogate pairs.
+    Json,
+}
+
+/// Displays the output of a value with all non-ASCII characters replaced by escape sequences.
+///
+/// Backslashes and double quotes are escaped as well such that the result can be placed in a
+/// string literal.
+///
+/// ```
+/// use indexing_fmt::*;
+/// use indexing_fmt::markup::EscapeStyle;
+///
+/// let res = format!("x{}", 2.to_superscript().escaped(EscapeStyle::Rust));
+/// assert_eq!(res, "x\\u{00B2}");
+///
+/// let res = format!("x{}", (-1).to_subscript().escaped(EscapeStyle::Json));
+/// assert_eq!(res, "x\\u208B\\u2081");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Escaped<D> {
+    value: D,
+    style: EscapeStyle,
+}
+
+impl<D> Escaped<D> {
+    pub fn new(value: D, style: EscapeStyle) -> Self {
+        Escaped { value, style }
+    }
+}
+
+impl<T> Superscript<T> {
+    /// See [Escaped].
+    pub fn escaped(self, style: EscapeStyle) -> Escaped<Self> {
+        Escaped::new(self, style)
+    }
+}
+
+impl<T> Subscript<T> {
+    /// See [Escaped].
+    pub fn escaped(self, style: EscapeStyle) -> Escaped<Self> {
+        Escaped::new(self, style)
+    }
+}
+
+struct EscapeSequences<'a, W> {
+    inner: &'a mut W,
+    style: EscapeStyle,
+}
+
+impl<W: Write> Write for EscapeSequences<'_, W> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        s.chars().try_for_each(|c| self.write_char(c))
+    }
+
+    fn write_char(&mut self, c: char) -> core::fmt::Result {
+        match (self.style, c) {
+            (_, '\\' | '"') => write!(self.inner, "\\{}", c),
+            (_, c) if c.is_ascii() => self.inner.write_char(c),
+            (EscapeStyle::Rust, c) => write!(self.inner, "\\u{{{:04X}}}", c as u32),
+            (EscapeStyle::Json, c) => {
+                let mut units = [0; 2];
+                for unit in c.encode_utf16(&mut units) {
+                    write!(self.inner, "\\u{:04X}", unit)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<D: Display> Display for Escaped<D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            EscapeSequences {
+                inner: f,
+                style: self.style,
+            },
+            "{}",
+            self.value
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::*;
+    extern crate std;
+
+    #[test]
+    fn pandoc_negative() {
+        let res = std::format!("{}", (-4).to_superscript().markup(Markup::Pandoc));
+        assert_eq!(res, "^-4^");
+        let res = std::format!("{}", 840.to_subscript().markup(Markup::Pandoc));
+        assert_eq!(res, "~840~");
+    }
+
+    #[test]
+    fn escape_special_characters() {
+        let res = std::format!("{}", Superscript("a b").markup(Markup::Pandoc));
+        assert_eq!(res, "^a\\ b^");
+        let res = std::format!("{}", Subscript("<i>").markup(Markup::Html));
+        assert_eq!(res, "<sub>&lt;i&gt;</sub>");
+    }
+
+    #[test]
+    fn escape_sequences_outside_bmp() {
+        let res = std::format!("{}", Escaped::new("𝟏", EscapeStyle::Json));
+        assert_eq!(res, "\\uD835\\uDFCF");
+        let res = std::format!("{}", Escaped::new("\"𝟏\"", EscapeStyle::Rust));
+        assert_eq!(res, "\\\"\\u{1D7CF}\\\"");
+    }
+}
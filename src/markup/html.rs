@@ -0,0 +1,241 @@
+//! Conversion of HTML `<sup>` and `<sub>` fragments into Unicode scripts.
+//!
+//! ```
+//! use indexing_fmt::markup::html::FromHtml;
+//!
+//! let res = format!("{}", FromHtml::new("x<sup>12</sup> + H<sub>2</sub>O"));
+//! assert_eq!(res, "x¹² + H₂O");
+//!
+//! let res = format!("{}", FromHtml::new("a<sup>&#8722;1</sup> &amp; 10<sup>&#x33;</sup>"));
+//! assert_eq!(res, "a⁻¹ & 10³");
+//! ```
+
+use core::fmt::{Display, Write};
+
+use crate::ScriptPosition;
+use crate::chars::{Fallback, UnicodeVersion, script_char};
+use crate::parse::{LimitExceeded, Limits};
+
+/// Reasons why HTML could not be converted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HtmlError {
+    /// A [Limits] was exceeded.
+    Limit(LimitExceeded),
+    /// A `<sup>` or `<sub>` tag was opened inside another one.
+    Nested,
+    /// A closing tag does not match the open tag.
+    Mismatched,
+    /// The input ends inside of a `<sup>` or `<sub>` element.
+    Unclosed,
+    /// A numeric character reference does not denote a character.
+    InvalidReference,
+    /// The character has no script form and the fallback is [Fallback::Error].
+    Unmappable(char),
+    /// The output could not be written.
+    Fmt,
+}
+
+impl From<LimitExceeded> for HtmlError {
+    fn from(limit: LimitExceeded) -> Self {
+        HtmlError::Limit(limit)
+    }
+}
+
+impl From<core::fmt::Error> for HtmlError {
+    fn from(_: core::fmt::Error) -> Self {
+        HtmlError::Fmt
+    }
+}
+
+impl Display for HtmlError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            HtmlError::Limit(limit) => write!(f, "{}", limit),
+            HtmlError::Nested => f.write_str("nested script elements"),
+            HtmlError::Mismatched => f.write_str("closing tag does not match the open element"),
+            HtmlError::Unclosed => f.write_str("unclosed script element"),
+            HtmlError::InvalidReference => f.write_str("invalid numeric character reference"),
+            HtmlError::Unmappable(c) => write!(f, "{:?} has no script form", c),
+            HtmlError::Fmt => f.write_str("error while writing the output"),
+        }
+    }
+}
+
+impl core::error::Error for HtmlError {}
+
+/// Replaces `<sup>` and `<sub>` elements by their Unicode script characters.
+///
+/// Numeric character references such as `&#178;` and the named references `&amp;`, `&lt;`,
+/// `&gt;`, `&quot;` and `&apos;` are decoded. All other tags and references, including `&#` without
+/// digits or closing `;`, are kept unchanged.
+/// Displaying fails with [core::fmt::Error] wherever [FromHtml::write_to] returns an error.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FromHtml<'a> {
+    input: &'a str,
+    limits: Limits,
+    fallback: Fallback,
+    unicode_version: UnicodeVersion,
+}
+
+impl<'a> FromHtml<'a> {
+    pub fn new(input: &'a str) -> Self {
+        FromHtml {
+            input,
+            limits: Limits::DEFAULT,
+            fallback: Fallback::Keep,
+            unicode_version: UnicodeVersion::V6_0,
+        }
+    }
+
+    pub fn limits(self, limits: Limits) -> Self {
+        FromHtml { limits, ..self }
+    }
+
+    /// Determines how characters without script form inside of elements are written.
+    pub fn fallback(self, fallback: Fallback) -> Self {
+        FromHtml { fallback, ..self }
+    }
+
+    /// Restricts or extends the available script characters, see [UnicodeVersion].
+    pub fn unicode_version(self, unicode_version: UnicodeVersion) -> Self {
+        FromHtml {
+            unicode_version,
+            ..self
+        }
+    }
+
+    /// Writes the converted text.
+    ///
+    /// ```
+    /// # use indexing_fmt::markup::html::*;
+    /// let mut res = String::new();
+    /// let error = FromHtml::new("x<sup>2</sub>").write_to(&mut res);
+    /// assert_eq!(error, Err(HtmlError::Mismatched));
+    /// ```
+    pub fn write_to(&self, out: &mut impl Write) -> Result<(), HtmlError> {
+        self.limits.check_input(self.input)?;
+        let mut open = None;
+        let mut rest = self.input;
+        while let Some(c) = rest.chars().next() {
+            if let Some((tag, position, closing)) = script_tag(rest) {
+                open = match (open, closing) {
+                    (None, false) => Some(position),
+                    (Some(_), false) => return Err(HtmlError::Nested),
+                    (Some(p), true) if p == position => None,
+                    (_, true) => return Err(HtmlError::Mismatched),
+                };
+                rest = &rest[tag..];
+                continue;
+            }
+            let (c, len) = match c {
+                '&' => self.reference(rest)?.unwrap_or((c, 1)),
+                c => (c, c.len_utf8()),
+            };
+            rest = &rest[len..];
+            let Some(position) = open else {
+                out.write_char(c)?;
+                continue;
+            };
+            match (
+                script_char(c, position, self.unicode_version),
+                self.fallback,
+            ) {
+                (Some(s), _) => out.write_char(s)?,
+                (None, Fallback::Error) => return Err(HtmlError::Unmappable(c)),
+                (None, fallback) => fallback.write(out, c)?,
+            }
+        }
+        match open {
+            Some(_) => Err(HtmlError::Unclosed),
+            None => Ok(()),
+        }
+    }
+
+    /// Decodes the character reference at the start of `text`, returning the character and the
+    /// length of the reference.
+    fn reference(&self, text: &str) -> Result<Option<(char, usize)>, HtmlError> {
+        const NAMED: [(&str, char); 5] = [
+            ("&amp;", '&'),
+            ("&lt;", '<'),
+            ("&gt;", '>'),
+            ("&quot;", '"'),
+            ("&apos;", '\''),
+        ];
+        if let Some((name, c)) = NAMED.iter().find(|(name, _)| text.starts_with(name)) {
+            return Ok(Some((*c, name.len())));
+        }
+        let Some(number) = text.strip_prefix("&#") else {
+            return Ok(None);
+        };
+        let (digits, radix) = match number.strip_prefix(['x', 'X']) {
+            Some(hex) => (hex, 16),
+            None => (number, 10),
+        };
+        let count = digits.chars().take_while(|c| c.is_digit(radix)).count();
+        self.limits.check_digits(count)?;
+        if count == 0 || !digits[count..].starts_with(';') {
+            return Ok(None);
+        }
+        let c = u32::from_str_radix(&digits[..count], radix)
+            .ok()
+            .and_then(char::from_u32)
+            .ok_or(HtmlError::InvalidReference)?;
+        Ok(Some((c, text.len() - digits.len() + count + 1)))
+    }
+}
+
+/// Recognizes `<sup>`, `</sup>`, `<sub>` and `</sub>` at the start of the text, ignoring case.
+fn script_tag(text: &str) -> Option<(usize, ScriptPosition, bool)> {
+    if !text.starts_with('<') {
+        return None;
+    }
+    let closing = text.starts_with("</");
+    let name_start = if closing { 2 } else { 1 };
+    let tag = text.get(..name_start + 4)?;
+    let position = match tag.get(name_start..)? {
+        name if name.eq_ignore_ascii_case("sup>") => ScriptPosition::Superscript,
+        name if name.eq_ignore_ascii_case("sub>") => ScriptPosition::Subscript,
+        _ => return None,
+    };
+    Some((tag.len(), position, closing))
+}
+
+impl Display for FromHtml<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.write_to(f).map_err(|_| core::fmt::Error)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    extern crate std;
+
+    fn convert(input: &str) -> Result<std::string::String, HtmlError> {
+        let mut res = std::string::String::new();
+        FromHtml::new(input).write_to(&mut res).map(|_| res)
+    }
+
+    #[test]
+    fn tags_and_errors() {
+        assert_eq!(convert("<SUP>n</SUP><b>x</b>").unwrap(), "ⁿ<b>x</b>");
+        assert_eq!(convert("xsup>2").unwrap(), "xsup>2");
+        assert_eq!(convert("<sub>1<sup>2</sup></sub>"), Err(HtmlError::Nested));
+        assert_eq!(convert("<sub>1"), Err(HtmlError::Unclosed));
+        assert_eq!(convert("&#xD800;"), Err(HtmlError::InvalidReference));
+        assert_eq!(convert("&#12").unwrap(), "&#12");
+        assert_eq!(convert("<sup>&#;2</sup>").unwrap(), "&#;²");
+        assert_eq!(convert("&nbsp;").unwrap(), "&nbsp;");
+    }
+
+    #[test]
+    fn limits_and_fallback() {
+        let html = FromHtml::new("&#0000000065;").limits(Limits::DEFAULT.max_digits(4));
+        let mut res = std::string::String::new();
+        let error = html.write_to(&mut res);
+        assert_eq!(error, Err(HtmlError::Limit(LimitExceeded::Digits)));
+
+        let html = FromHtml::new("<sub>q</sub>").fallback(Fallback::Error);
+        assert_eq!(html.write_to(&mut res), Err(HtmlError::Unmappable('q')));
+    }
+}
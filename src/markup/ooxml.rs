@@ -0,0 +1,106 @@
+//! Runs of WordprocessingML as found in the `document.xml` of DOCX files.
+//!
+//! This module requires the `ooxml` feature.
+//!
+//! ```
+//! use indexing_fmt::*;
+//! use indexing_fmt::markup::ooxml::Run;
+//!
+//! let xml = format!("{}{}", Run::new("Ship"), 12.to_superscript().ooxml_run());
+//! assert_eq!(
+//!     xml,
+//!     "<w:r><w:t xml:space=\"preserve\">Ship</w:t></w:r>\
+//!     <w:r><w:rPr><w:vertAlign w:val=\"superscript\"/></w:rPr>\
+//!     <w:t xml:space=\"preserve\">12</w:t></w:r>"
+//! );
+//! ```
+
+use core::fmt::{Display, Write};
+
+use super::{Escape, Markup};
+use crate::{ScriptPosition, Subscript, Superscript};
+
+/// A `<w:r>` element containing text which is optionally vertically aligned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Run<T> {
+    text: T,
+    position: Option<ScriptPosition>,
+}
+
+impl<T> Run<T> {
+    /// A run on the baseline.
+    pub fn new(text: T) -> Self {
+        Run {
+            text,
+            position: None,
+        }
+    }
+}
+
+impl<T> Superscript<T> {
+    /// See the [module](crate::markup::ooxml) level documentation.
+    pub fn ooxml_run(self) -> Run<T> {
+        Run {
+            text: self.0,
+            position: Some(ScriptPosition::Superscript),
+        }
+    }
+}
+
+impl<T> Subscript<T> {
+    /// See the [module](crate::markup::ooxml) level documentation.
+    pub fn ooxml_run(self) -> Run<T> {
+        Run {
+            text: self.0,
+            position: Some(ScriptPosition::Subscript),
+        }
+    }
+}
+
+impl<T: Display> Display for Run<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("<w:r>")?;
+        match self.position {
+            Some(ScriptPosition::Superscript) => {
+                f.write_str("<w:rPr><w:vertAlign w:val=\"superscript\"/></w:rPr>")?
+            }
+            Some(ScriptPosition::Subscript) => {
+                f.write_str("<w:rPr><w:vertAlign w:val=\"subscript\"/></w:rPr>")?
+            }
+            None => (),
+        }
+        f.write_str("<w:t xml:space=\"preserve\">")?;
+        write!(
+            Escape {
+                inner: f,
+                markup: Markup::Html,
+            },
+            "{}",
+            self.text
+        )?;
+        f.write_str("</w:t></w:r>")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::*;
+    extern crate std;
+
+    #[test]
+    fn subscript_run() {
+        let res = std::format!("{}", (-3).to_subscript().ooxml_run());
+        assert_eq!(
+            res,
+            "<w:r><w:rPr><w:vertAlign w:val=\"subscript\"/></w:rPr>\
+            <w:t xml:space=\"preserve\">-3</w:t></w:r>"
+        );
+    }
+
+    #[test]
+    fn escape_base_text() {
+        let res = std::format!("{}", Run::new("R&D"));
+        assert_eq!(res, "<w:r><w:t xml:space=\"preserve\">R&amp;D</w:t></w:r>");
+    }
+}
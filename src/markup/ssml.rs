@@ -0,0 +1,307 @@
+//! `<sub>` elements of the [Speech Synthesis Markup Language](https://www.w3.org/TR/speech-synthesis11/)
+//! which tell speech engines how to pronounce scripts.
+//!
+//! The written form of the script is kept as the content of the element while the `alias`
+//! attribute contains the English words which should be spoken instead. Subscripts are read as
+//! cardinal numbers and superscripts as exponents.
+//!
+//! ```
+//! use indexing_fmt::*;
+//!
+//! let res = format!("H{}O", 2.to_subscript().ssml_sub());
+//! assert_eq!(res, "H<sub alias=\"two\">₂</sub>O");
+//!
+//! let res = format!("10{}", 3.to_superscript().ssml_sub());
+//! assert_eq!(res, "10<sub alias=\"to the third\">³</sub>");
+//! ```
+
+use core::fmt::{Display, Write};
+
+use super::{Escape, Markup};
+use crate::{ScriptPosition, Subscript, Superscript};
+
+/// A `<sub>` element whose `alias` attribute spells out the script in words.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Sub<T> {
+    value: T,
+    position: ScriptPosition,
+}
+
+impl<T> Superscript<T> {
+    /// See the [module](crate::markup::ssml) level documentation.
+    pub fn ssml_sub(self) -> Sub<T> {
+        Sub {
+            value: self.0,
+            position: ScriptPosition::Superscript,
+        }
+    }
+}
+
+impl<T> Subscript<T> {
+    /// See the [module](crate::markup::ssml) level documentation.
+    pub fn ssml_sub(self) -> Sub<T> {
+        Sub {
+            value: self.0,
+            position: ScriptPosition::Subscript,
+        }
+    }
+}
+
+/// Collects the decimal output of an integer.
+#[derive(Default)]
+struct Integer {
+    negative: bool,
+    magnitude: u128,
+    digits: usize,
+    invalid: bool,
+}
+
+impl Integer {
+    fn is_valid(&self) -> bool {
+        self.digits > 0 && !self.invalid
+    }
+}
+
+impl Write for Integer {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        for c in s.chars() {
+            match c.to_digit(10) {
+                None if c == '-' && self.digits == 0 && !self.negative => self.negative = true,
+                Some(digit) => {
+                    self.digits += 1;
+                    let magnitude = self.magnitude.checked_mul(10);
+                    match magnitude.and_then(|m| m.checked_add(u128::from(digit))) {
+                        Some(m) => self.magnitude = m,
+                        None => self.invalid = true,
+                    }
+                }
+                None => self.invalid = true,
+            }
+        }
+        Ok(())
+    }
+}
+
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+/// Short scale names of the powers of one thousand which fit into [u128].
+const SCALES: [&str; 13] = [
+    "",
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+    "sextillion",
+    "septillion",
+    "octillion",
+    "nonillion",
+    "decillion",
+    "undecillion",
+];
+
+/// Writes words separated by spaces and holds back the last one such that it can be turned into
+/// an ordinal.
+struct Words<'a, W> {
+    inner: &'a mut W,
+    pending: Option<&'static str>,
+}
+
+impl<W: Write> Words<'_, W> {
+    fn push(&mut self, word: &'static str) -> core::fmt::Result {
+        if let Some(pending) = self.pending.replace(word) {
+            self.inner.write_str(pending)?;
+            self.inner.write_char(' ')?;
+        }
+        Ok(())
+    }
+
+    fn push_number(&mut self, n: u128) -> core::fmt::Result {
+        if n == 0 {
+            return self.push(ONES[0]);
+        }
+        let mut scale = 0;
+        while (scale as usize) < SCALES.len() - 1 && n / 1000u128.pow(scale + 1) > 0 {
+            scale += 1;
+        }
+        for scale in (0..=scale).rev() {
+            let group = (n / 1000u128.pow(scale) % 1000) as usize;
+            if group == 0 {
+                continue;
+            }
+            let (hundreds, rest) = (group / 100, group % 100);
+            if hundreds > 0 {
+                self.push(ONES[hundreds])?;
+                self.push("hundred")?;
+            }
+            match rest {
+                0 => (),
+                1..20 => self.push(ONES[rest])?,
+                _ => {
+                    self.push(TENS[rest / 10])?;
+                    if rest % 10 > 0 {
+                        self.push(ONES[rest % 10])?;
+                    }
+                }
+            }
+            if scale > 0 {
+                self.push(SCALES[scale as usize])?;
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(self, ordinal: bool) -> core::fmt::Result {
+        let Some(word) = self.pending else {
+            return Ok(());
+        };
+        if !ordinal {
+            return self.inner.write_str(word);
+        }
+        match word {
+            "one" => self.inner.write_str("first"),
+            "two" => self.inner.write_str("second"),
+            "three" => self.inner.write_str("third"),
+            "five" => self.inner.write_str("fifth"),
+            "eight" => self.inner.write_str("eighth"),
+            "nine" => self.inner.write_str("ninth"),
+            "twelve" => self.inner.write_str("twelfth"),
+            word if word.ends_with('y') => {
+                self.inner.write_str(&word[..word.len() - 1])?;
+                self.inner.write_str("ieth")
+            }
+            word => {
+                self.inner.write_str(word)?;
+                self.inner.write_str("th")
+            }
+        }
+    }
+}
+
+/// Escapes text in the attribute and the content of the element like HTML.
+fn escaped<'a, W: Write>(inner: &'a mut W) -> Escape<'a, W> {
+    Escape {
+        inner,
+        markup: Markup::Html,
+    }
+}
+
+impl<T> Display for Sub<T>
+where
+    T: Copy + Display,
+    Superscript<T>: Display,
+    Subscript<T>: Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut integer = Integer::default();
+        write!(integer, "{}", self.value)?;
+
+        f.write_str("<sub alias=\"")?;
+        if self.position == ScriptPosition::Superscript {
+            f.write_str("to the ")?;
+        }
+        if integer.is_valid() {
+            let mut words = Words {
+                inner: f,
+                pending: None,
+            };
+            if integer.negative {
+                words.push("minus")?;
+            }
+            words.push_number(integer.magnitude)?;
+            words.finish(self.position == ScriptPosition::Superscript)?;
+        } else {
+            write!(escaped(f), "{}", self.value)?;
+        }
+        f.write_str("\">")?;
+        match self.position {
+            ScriptPosition::Superscript => write!(escaped(f), "{}", Superscript(self.value))?,
+            ScriptPosition::Subscript => write!(escaped(f), "{}", Subscript(self.value))?,
+        }
+        f.write_str("</sub>")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    extern crate std;
+
+    fn alias<D: core::fmt::Display>(value: D) -> std::string::String {
+        let res = std::format!("{}", value);
+        res.split('"').nth(1).unwrap().into()
+    }
+
+    #[test]
+    fn cardinal_subscripts() {
+        assert_eq!(alias(0.to_subscript().ssml_sub()), "zero");
+        assert_eq!(alias(17.to_subscript().ssml_sub()), "seventeen");
+        assert_eq!(alias((-40).to_subscript().ssml_sub()), "minus forty");
+        assert_eq!(
+            alias(1_000_023.to_subscript().ssml_sub()),
+            "one million twenty three"
+        );
+        assert_eq!(
+            alias(u64::MAX.to_subscript().ssml_sub()),
+            "eighteen quintillion four hundred forty six quadrillion seven hundred forty four \
+             trillion seventy three billion seven hundred nine million five hundred fifty one \
+             thousand six hundred fifteen"
+        );
+    }
+
+    #[test]
+    fn ordinal_exponents() {
+        assert_eq!(alias(2.to_superscript().ssml_sub()), "to the second");
+        assert_eq!(
+            alias((-1).to_superscript().ssml_sub()),
+            "to the minus first"
+        );
+        assert_eq!(alias(12.to_superscript().ssml_sub()), "to the twelfth");
+        assert_eq!(alias(20.to_superscript().ssml_sub()), "to the twentieth");
+        assert_eq!(
+            alias(100.to_superscript().ssml_sub()),
+            "to the one hundredth"
+        );
+        assert_eq!(alias(0.to_superscript().ssml_sub()), "to the zeroth");
+    }
+
+    #[test]
+    fn escaped_text() {
+        let res = std::format!("{}", Superscript("a\"b<c").ssml_sub());
+        assert_eq!(
+            res,
+            "<sub alias=\"to the a&quot;b&lt;c\">ᵃ&quot;ᵇ&lt;ᶜ</sub>"
+        );
+        let res = std::format!("{}", Subscript("</sub>&").ssml_sub());
+        assert_eq!(
+            res,
+            "<sub alias=\"&lt;/sub&gt;&amp;\">&lt;/ₛᵤb&gt;&amp;</sub>"
+        );
+    }
+}
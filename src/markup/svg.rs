@@ -0,0 +1,95 @@
+//! `<tspan>` elements to place scripts inside of SVG `<text>` elements.
+//!
+//! ```
+//! use indexing_fmt::*;
+//!
+//! let label = format!("<text>x{}</text>", 2.to_superscript().svg_tspan());
+//! assert_eq!(
+//!     label,
+//!     "<text>x<tspan baseline-shift=\"super\" font-size=\"70%\">2</tspan></text>"
+//! );
+//! ```
+
+use core::fmt::{Display, Write};
+
+use super::{Escape, Markup};
+use crate::{ScriptPosition, Subscript, Superscript};
+
+/// A `<tspan>` element which shifts its text to the superscript or subscript position.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Tspan<'a, T> {
+    text: T,
+    position: ScriptPosition,
+    font_size: &'a str,
+}
+
+impl<'a, T> Tspan<'a, T> {
+    /// Sets the value of the `font-size` attribute which defaults to `70%`.
+    pub fn font_size(self, font_size: &'a str) -> Self {
+        Tspan { font_size, ..self }
+    }
+}
+
+impl<T> Superscript<T> {
+    /// See the [module](crate::markup::svg) level documentation.
+    pub fn svg_tspan<'a>(self) -> Tspan<'a, T> {
+        Tspan {
+            text: self.0,
+            position: ScriptPosition::Superscript,
+            font_size: "70%",
+        }
+    }
+}
+
+impl<T> Subscript<T> {
+    /// See the [module](crate::markup::svg) level documentation.
+    pub fn svg_tspan<'a>(self) -> Tspan<'a, T> {
+        Tspan {
+            text: self.0,
+            position: ScriptPosition::Subscript,
+            font_size: "70%",
+        }
+    }
+}
+
+impl<T: Display> Display for Tspan<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let shift = match self.position {
+            ScriptPosition::Superscript => "super",
+            ScriptPosition::Subscript => "sub",
+        };
+        f.write_str("<tspan baseline-shift=\"")?;
+        f.write_str(shift)?;
+        f.write_str("\" font-size=\"")?;
+        let mut escape = Escape {
+            inner: f,
+            markup: Markup::Html,
+        };
+        escape.write_str(self.font_size)?;
+        f.write_str("\">")?;
+        write!(
+            Escape {
+                inner: f,
+                markup: Markup::Html,
+            },
+            "{}",
+            self.text
+        )?;
+        f.write_str("</tspan>")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    extern crate std;
+
+    #[test]
+    fn subscript_font_size() {
+        let res = std::format!("{}", 12.to_subscript().svg_tspan().font_size("8px"));
+        assert_eq!(
+            res,
+            "<tspan baseline-shift=\"sub\" font-size=\"8px\">12</tspan>"
+        );
+    }
+}
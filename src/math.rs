@@ -0,0 +1,532 @@
+//! Notation commonly found in mathematics.
+//!
+//! ```
+//! use indexing_fmt::math::*;
+//!
+//! let series = PowerSeries::symbolic("a", 3);
+//! assert_eq!(format!("{}", series), "a₀ + a₁x + a₂x² + …");
+//! ```
+
+use core::fmt::{Display, Write};
+
+use crate::Spacing;
+use crate::chars::{Fallback, superscript_char, write_mapped};
+use crate::{FormatSubscript, FormatSuperscript, Superscript};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Coefficients<'a, T> {
+    Symbolic { name: &'a str, terms: usize },
+    Values(&'a [T]),
+}
+
+/// Displays the first terms of a power series `a₀ + a₁x + a₂x² + …`.
+///
+/// Coefficients can either be symbolic with subscripted indices or given as values which are
+/// displayed as they are.
+///
+/// ```
+/// # use indexing_fmt::math::*;
+/// let series = PowerSeries::new(&[1, 3, 5, 7]).variable("z").ellipsis(false);
+/// assert_eq!(format!("{}", series), "1 + 3z + 5z² + 7z³");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PowerSeries<'a, T> {
+    coefficients: Coefficients<'a, T>,
+    variable: &'a str,
+    ellipsis: bool,
+}
+
+impl<'a> PowerSeries<'a, &'a str> {
+    /// Symbolic coefficients `name₀, name₁, …` for the given number of terms.
+    pub fn symbolic(name: &'a str, terms: usize) -> Self {
+        PowerSeries {
+            coefficients: Coefficients::Symbolic { name, terms },
+            variable: "x",
+            ellipsis: true,
+        }
+    }
+}
+
+impl<'a, T> PowerSeries<'a, T> {
+    pub fn new(coefficients: &'a [T]) -> Self {
+        PowerSeries {
+            coefficients: Coefficients::Values(coefficients),
+            variable: "x",
+            ellipsis: true,
+        }
+    }
+
+    /// Sets the variable of the series which defaults to `x`.
+    pub fn variable(self, variable: &'a str) -> Self {
+        PowerSeries { variable, ..self }
+    }
+
+    /// Enables or disables the trailing ` + …` which is shown by default.
+    pub fn ellipsis(self, ellipsis: bool) -> Self {
+        PowerSeries { ellipsis, ..self }
+    }
+}
+
+impl<T: Display> Display for PowerSeries<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let terms = match self.coefficients {
+            Coefficients::Symbolic { terms, .. } => terms,
+            Coefficients::Values(values) => values.len(),
+        };
+        for n in 0..terms {
+            if n > 0 {
+                f.write_str(" + ")?;
+            }
+            match self.coefficients {
+                Coefficients::Symbolic { name, .. } => write!(f, "{}{}", name, n.to_subscript())?,
+                Coefficients::Values(values) => write!(f, "{}", values[n])?,
+            }
+            match n {
+                0 => (),
+                1 => f.write_str(self.variable)?,
+                n => write!(f, "{}{}", self.variable, n.to_superscript())?,
+            }
+        }
+        if self.ellipsis {
+            f.write_str(if terms > 0 { " + …" } else { "…" })?;
+        }
+        Ok(())
+    }
+}
+
+/// The order of a [Derivative].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Order<'a> {
+    Numeric(u32),
+    /// Characters without superscript form are written unchanged.
+    Symbolic(&'a str),
+}
+
+impl From<u32> for Order<'_> {
+    fn from(order: u32) -> Self {
+        Order::Numeric(order)
+    }
+}
+
+impl<'a> From<&'a str> for Order<'a> {
+    fn from(order: &'a str) -> Self {
+        Order::Symbolic(order)
+    }
+}
+
+/// Displays the derivative of a function as `f⁽ⁿ⁾(x)`.
+///
+/// ```
+/// # use indexing_fmt::math::*;
+/// assert_eq!(format!("{}", Derivative::new("f", 4)), "f⁽⁴⁾");
+/// assert_eq!(format!("{}", Derivative::new("g", "n+1").argument("x")), "g⁽ⁿ⁺¹⁾(x)");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Derivative<'a> {
+    function: &'a str,
+    order: Order<'a>,
+    argument: Option<&'a str>,
+    primes: Option<u32>,
+}
+
+impl<'a> Derivative<'a> {
+    pub fn new(function: &'a str, order: impl Into<Order<'a>>) -> Self {
+        Derivative {
+            function,
+            order: order.into(),
+            argument: None,
+            primes: None,
+        }
+    }
+
+    /// Writes numeric orders up to the limit with prime marks like `f‴`, see [Primes].
+    ///
+    /// ```
+    /// # use indexing_fmt::math::*;
+    /// assert_eq!(format!("{}", Derivative::new("f", 3).primes(3).argument("x")), "f‴(x)");
+    /// assert_eq!(format!("{}", Derivative::new("f", 4).primes(3)), "f⁽⁴⁾");
+    /// ```
+    pub fn primes(self, limit: u32) -> Self {
+        Derivative {
+            primes: Some(limit),
+            ..self
+        }
+    }
+
+    /// Appends the argument in parentheses.
+    pub fn argument(self, argument: &'a str) -> Self {
+        Derivative {
+            argument: Some(argument),
+            ..self
+        }
+    }
+}
+
+/// The superscript order `⁽ⁿ⁾` of a derivative.
+fn write_parenthesized(f: &mut core::fmt::Formatter<'_>, n: u32) -> core::fmt::Result {
+    let (open, close) = (Superscript('('), Superscript(')'));
+    write!(f, "{}{}{}", open, n.to_superscript(), close)
+}
+
+impl Display for Derivative<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.function)?;
+        match (self.order, self.primes) {
+            (Order::Numeric(n), Some(limit)) => {
+                write!(f, "{}", primes(n).parenthesized_above(limit))?
+            }
+            (Order::Numeric(n), None) => write_parenthesized(f, n)?,
+            (Order::Symbolic(s), _) => {
+                write!(f, "{}", Superscript('('))?;
+                write_mapped(f, s, superscript_char, Fallback::Keep)?;
+                write!(f, "{}", Superscript(')'))?;
+            }
+        }
+        if let Some(argument) = self.argument {
+            write!(f, "({})", argument)?;
+        }
+        Ok(())
+    }
+}
+
+/// Displays prime marks `′ ″ ‴ ⁗` as in `x′`, `f‴` or `5′`.
+///
+/// More than four primes are combined from quadruple primes and the remainder, unless a limit is
+/// set with [parenthesized_above](Primes::parenthesized_above).
+///
+/// ```
+/// # use indexing_fmt::math::*;
+/// assert_eq!(format!("x{}", primes(1)), "x′");
+/// assert_eq!(format!("f{}", primes(6)), "f⁗″");
+/// assert_eq!(format!("f{}", primes(6).parenthesized_above(3)), "f⁽⁶⁾");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Primes {
+    count: u32,
+    limit: Option<u32>,
+}
+
+/// Starts [Primes] with the given number of prime marks.
+pub fn primes(count: u32) -> Primes {
+    Primes { count, limit: None }
+}
+
+impl Primes {
+    /// Writes the count as parenthesized superscript like `⁽⁵⁾` if it exceeds the limit.
+    pub fn parenthesized_above(self, limit: u32) -> Self {
+        Primes {
+            limit: Some(limit),
+            ..self
+        }
+    }
+}
+
+impl Display for Primes {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        const PRIMES: [char; 4] = ['\u{2032}', '\u{2033}', '\u{2034}', '\u{2057}'];
+        if self.limit.is_some_and(|limit| self.count > limit) {
+            return write_parenthesized(f, self.count);
+        }
+        for _ in 0..self.count / 4 {
+            f.write_char(PRIMES[3])?;
+        }
+        match self.count % 4 {
+            0 => Ok(()),
+            n => f.write_char(PRIMES[n as usize - 1]),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Quotients<'a, I> {
+    Symbolic { name: &'a str, terms: usize },
+    Values(I),
+}
+
+/// Displays a continued fraction in the compact notation `[a₀; a₁, a₂, …]`.
+///
+/// The partial quotients are given by an iterator which may be infinite if the number of
+/// displayed terms is limited by [ContinuedFraction::max_terms].
+///
+/// ```
+/// # use indexing_fmt::math::*;
+/// let sqrt2 = core::iter::once(1).chain(core::iter::repeat(2));
+/// assert_eq!(format!("{}", ContinuedFraction::new(sqrt2).max_terms(4)), "[1; 2, 2, 2, …]");
+///
+/// let symbolic = ContinuedFraction::symbolic("a", 3).ellipsis(true);
+/// assert_eq!(format!("{}", symbolic), "[a₀; a₁, a₂, …]");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ContinuedFraction<'a, I> {
+    quotients: Quotients<'a, I>,
+    max_terms: Option<usize>,
+    ellipsis: bool,
+}
+
+impl<'a> ContinuedFraction<'a, core::iter::Empty<u64>> {
+    /// Symbolic partial quotients `name₀, name₁, …` for the given number of terms.
+    pub fn symbolic(name: &'a str, terms: usize) -> Self {
+        ContinuedFraction {
+            quotients: Quotients::Symbolic { name, terms },
+            max_terms: None,
+            ellipsis: false,
+        }
+    }
+}
+
+impl<I> ContinuedFraction<'_, I>
+where
+    I: Iterator + Clone,
+    I::Item: Display,
+{
+    pub fn new(quotients: impl IntoIterator<IntoIter = I>) -> Self {
+        ContinuedFraction {
+            quotients: Quotients::Values(quotients.into_iter()),
+            max_terms: None,
+            ellipsis: false,
+        }
+    }
+}
+
+impl<I> ContinuedFraction<'_, I> {
+    /// Limits the number of displayed terms, appending an ellipsis if terms were omitted.
+    pub fn max_terms(self, max_terms: usize) -> Self {
+        ContinuedFraction {
+            max_terms: Some(max_terms),
+            ..self
+        }
+    }
+
+    /// Always appends an ellipsis to indicate that the fraction continues.
+    pub fn ellipsis(self, ellipsis: bool) -> Self {
+        ContinuedFraction { ellipsis, ..self }
+    }
+}
+
+impl<I> Display for ContinuedFraction<'_, I>
+where
+    I: Iterator + Clone,
+    I::Item: Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let max_terms = self.max_terms.unwrap_or(usize::MAX);
+        let separator = |f: &mut core::fmt::Formatter<'_>, n: usize| match n {
+            0 => Ok(()),
+            1 => f.write_str("; "),
+            _ => f.write_str(", "),
+        };
+
+        f.write_char('[')?;
+        let (written, truncated) = match &self.quotients {
+            Quotients::Symbolic { name, terms } => {
+                for n in 0..(*terms).min(max_terms) {
+                    separator(f, n)?;
+                    write!(f, "{}{}", name, n.to_subscript())?;
+                }
+                ((*terms).min(max_terms), *terms > max_terms)
+            }
+            Quotients::Values(quotients) => {
+                let mut quotients = quotients.clone();
+                let mut n = 0;
+                while n < max_terms {
+                    match quotients.next() {
+                        Some(q) => {
+                            separator(f, n)?;
+                            write!(f, "{}", q)?;
+                        }
+                        None => break,
+                    }
+                    n += 1;
+                }
+                (n, n == max_terms && quotients.next().is_some())
+            }
+        };
+        if truncated || self.ellipsis {
+            separator(f, written)?;
+            f.write_char('…')?;
+        }
+        f.write_char(']')
+    }
+}
+
+/// Displays a symbol with several subscript indices like the matrix element `a₁‚₂`.
+///
+/// Unicode has no subscript comma, so the indices are separated by U+201A SINGLE LOW-9 QUOTATION
+/// MARK by default, which looks like a comma on the baseline. With [Spacing::None], the indices are
+/// written compactly as `a₁₂`, which is only unambiguous for single digits.
+///
+/// ```
+/// # use indexing_fmt::math::*;
+/// use indexing_fmt::Spacing;
+///
+/// assert_eq!(format!("{}", multi_sub("a", &[1, 2])), "a₁‚₂");
+/// assert_eq!(format!("{}", multi_sub("a", &[1, 2]).separator(Spacing::None)), "a₁₂");
+/// assert_eq!(format!("{}", multi_sub("T", &[10, 2]).separator(Spacing::Thin)), "T₁₀\u{2009}₂");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MultiIndex<'a> {
+    symbol: &'a str,
+    indices: &'a [u32],
+    separator: Spacing,
+}
+
+/// Starts a [MultiIndex].
+pub fn multi_sub<'a>(symbol: &'a str, indices: &'a [u32]) -> MultiIndex<'a> {
+    MultiIndex {
+        symbol,
+        indices,
+        separator: Spacing::Custom('\u{201a}'),
+    }
+}
+
+impl MultiIndex<'_> {
+    /// Replaces the separator between the indices.
+    pub fn separator(self, separator: Spacing) -> Self {
+        MultiIndex { separator, ..self }
+    }
+}
+
+impl Display for MultiIndex<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.symbol)?;
+        for (i, index) in self.indices.iter().enumerate() {
+            if i > 0 {
+                write!(f, "{}", self.separator)?;
+            }
+            write!(f, "{}", index.to_subscript())?;
+        }
+        Ok(())
+    }
+}
+
+/// Superscript letters which are common in exponents.
+///
+/// ```
+/// use indexing_fmt::math::sup;
+///
+/// assert_eq!(format!("x{}", sup::N), "xⁿ");
+/// assert_eq!(format!("e{}{}", sup::I, sup::T), "eⁱᵗ");
+/// ```
+pub mod sup {
+    /// `ⁱ` as in `eⁱᵗ`
+    pub const I: char = '\u{2071}';
+    /// `ʲ`
+    pub const J: char = '\u{02b2}';
+    /// `ᵏ` as in `xⁿ⁺ᵏ`
+    pub const K: char = '\u{1d4f}';
+    /// `ᵐ`
+    pub const M: char = '\u{1d50}';
+    /// `ⁿ` as in `xⁿ`
+    pub const N: char = '\u{207f}';
+    /// `ᵗ` as in the transpose `Aᵗ`
+    pub const T: char = '\u{1d57}';
+    /// `ˣ` as in `eˣ`
+    pub const X: char = '\u{02e3}';
+    /// `⁺`
+    pub const PLUS: char = '\u{207a}';
+    /// `⁻` as in the inverse `A⁻¹`
+    pub const MINUS: char = '\u{207b}';
+}
+
+/// Subscript letters which are common in indices.
+///
+/// ```
+/// use indexing_fmt::math::sub;
+///
+/// assert_eq!(format!("a{}{}", sub::I, sub::J), "aᵢⱼ");
+/// ```
+pub mod sub {
+    /// `ᵢ`
+    pub const I: char = '\u{1d62}';
+    /// `ⱼ`
+    pub const J: char = '\u{2c7c}';
+    /// `ₖ`
+    pub const K: char = '\u{2096}';
+    /// `ₘ`
+    pub const M: char = '\u{2098}';
+    /// `ₙ` as in `aₙ`
+    pub const N: char = '\u{2099}';
+    /// `ₜ`
+    pub const T: char = '\u{209c}';
+    /// `ₓ`
+    pub const X: char = '\u{2093}';
+    /// `₊`
+    pub const PLUS: char = '\u{208a}';
+    /// `₋`
+    pub const MINUS: char = '\u{208b}';
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    extern crate std;
+
+    #[test]
+    fn prime_marks() {
+        let res = [0, 2, 4, 5, 9].map(|n| std::format!("{}", primes(n)));
+        assert_eq!(res, ["", "″", "⁗", "⁗′", "⁗⁗′"]);
+        assert_eq!(std::format!("{}", primes(2).parenthesized_above(2)), "″");
+    }
+
+    #[test]
+    fn multi_indices() {
+        assert_eq!(std::format!("{}", multi_sub("g", &[])), "g");
+        assert_eq!(std::format!("{}", multi_sub("c", &[0, 11, 3])), "c₀‚₁₁‚₃");
+        let res = multi_sub("x", &[4, 2]).separator(Spacing::Custom(','));
+        assert_eq!(std::format!("{}", res), "x₄,₂");
+    }
+
+    #[test]
+    fn letter_constants() {
+        let sup = [sup::I, sup::J, sup::K, sup::M, sup::N, sup::T, sup::X];
+        let sub = [sub::I, sub::J, sub::K, sub::M, sub::N, sub::T, sub::X];
+        for (n, c) in "ijkmntx".chars().enumerate() {
+            assert_eq!(superscript_char(c), Some(sup[n]));
+            assert_eq!(crate::subscript_char(c), Some(sub[n]));
+        }
+    }
+
+    #[test]
+    fn power_series_symbolic() {
+        let series = PowerSeries::symbolic("c", 12).variable("t").ellipsis(false);
+        let res = std::format!("{}", series);
+        assert!(res.starts_with("c₀ + c₁t + c₂t² + "));
+        assert!(res.ends_with("c₁₁t¹¹"));
+    }
+
+    #[test]
+    fn power_series_empty() {
+        let series = PowerSeries::<f64>::new(&[]);
+        assert_eq!(std::format!("{}", series), "…");
+        assert_eq!(std::format!("{}", series.ellipsis(false)), "");
+    }
+
+    #[test]
+    fn derivative_orders() {
+        let res = std::format!("{}", Derivative::new("y", 12).argument("t"));
+        assert_eq!(res, "y⁽¹²⁾(t)");
+
+        let res = std::format!("{}", Derivative::new("f", "k-1"));
+        assert_eq!(res, "f⁽ᵏ⁻¹⁾");
+
+        // There is no superscript q
+        let res = std::format!("{}", Derivative::new("f", "q"));
+        assert_eq!(res, "f⁽q⁾");
+    }
+
+    #[test]
+    fn continued_fraction_finite() {
+        let res = std::format!("{}", ContinuedFraction::new([3, 7, 15, 1, 292]));
+        assert_eq!(res, "[3; 7, 15, 1, 292]");
+
+        let res = std::format!("{}", ContinuedFraction::new([4]));
+        assert_eq!(res, "[4]");
+
+        // Limiting to exactly the number of terms does not add an ellipsis
+        let res = std::format!("{}", ContinuedFraction::new([1, 2]).max_terms(2));
+        assert_eq!(res, "[1; 2]");
+
+        let res = std::format!("{}", ContinuedFraction::symbolic("b", 5).max_terms(3));
+        assert_eq!(res, "[b₀; b₁, b₂, …]");
+    }
+}
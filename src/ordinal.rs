@@ -0,0 +1,143 @@
+//! Ordinal numbers with superscript indicators.
+//!
+//! ```
+//! use indexing_fmt::ordinal::*;
+//!
+//! assert_eq!(format!("{} place", ordinal(2)), "2ⁿᵈ place");
+//! assert_eq!(format!("{} planta", RomanceOrdinal::new(3, Gender::Feminine)), "3ª planta");
+//! ```
+
+use core::fmt::Display;
+
+/// Displays an English ordinal number like `1ˢᵗ`, `2ⁿᵈ`, `3ʳᵈ` or `4ᵗʰ`.
+///
+/// The number itself is written with plain digits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ordinal(pub u128);
+
+/// Starts an English [Ordinal].
+pub fn ordinal(value: u128) -> Ordinal {
+    Ordinal(value)
+}
+
+impl Ordinal {
+    /// The superscript suffix of the number, for combining it with other number formatting.
+    ///
+    /// Numbers ending in 11, 12 or 13 take `ᵗʰ`.
+    ///
+    /// ```
+    /// # use indexing_fmt::ordinal::*;
+    /// assert_eq!(ordinal(21).suffix(), "ˢᵗ");
+    /// assert_eq!(ordinal(112).suffix(), "ᵗʰ");
+    /// ```
+    pub const fn suffix(self) -> &'static str {
+        self.suffixes().0
+    }
+
+    /// The superscript and the plain suffix.
+    const fn suffixes(self) -> (&'static str, &'static str) {
+        match (self.0 % 100, self.0 % 10) {
+            (11..=13, _) => ("\u{1d57}\u{02b0}", "th"),
+            (_, 1) => ("\u{02e2}\u{1d57}", "st"),
+            (_, 2) => ("\u{207f}\u{1d48}", "nd"),
+            (_, 3) => ("\u{02b3}\u{1d48}", "rd"),
+            _ => ("\u{1d57}\u{02b0}", "th"),
+        }
+    }
+}
+
+impl Display for Ordinal {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let (superscript, plain) = self.suffixes();
+        match crate::plain_mode() {
+            true => write!(f, "{}{}", self.0, plain),
+            false => write!(f, "{}{}", self.0, superscript),
+        }
+    }
+}
+
+/// The grammatical gender which selects the Romance ordinal indicator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Gender {
+    /// `º` U+00BA MASCULINE ORDINAL INDICATOR
+    Masculine,
+    /// `ª` U+00AA FEMININE ORDINAL INDICATOR
+    Feminine,
+}
+
+/// Displays an ordinal number of Spanish, Portuguese, Italian or Galician like `1º` or `2ª`.
+///
+/// Unlike English ordinals, the indicator only depends on the gender and not on the number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RomanceOrdinal {
+    value: u128,
+    gender: Gender,
+    full_stop: bool,
+}
+
+impl RomanceOrdinal {
+    pub fn new(value: u128, gender: Gender) -> Self {
+        RomanceOrdinal {
+            value,
+            gender,
+            full_stop: false,
+        }
+    }
+
+    /// Writes a full stop in front of the indicator like `1.º`, as recommended for Spanish and
+    /// Portuguese.
+    ///
+    /// ```
+    /// # use indexing_fmt::ordinal::*;
+    /// assert_eq!(format!("{}", RomanceOrdinal::new(1, Gender::Masculine).full_stop()), "1.º");
+    /// ```
+    pub fn full_stop(self) -> Self {
+        RomanceOrdinal {
+            full_stop: true,
+            ..self
+        }
+    }
+
+    /// The ordinal indicator, for combining it with other number formatting.
+    pub const fn indicator(self) -> char {
+        match self.gender {
+            Gender::Masculine => '\u{ba}',
+            Gender::Feminine => '\u{aa}',
+        }
+    }
+}
+
+impl Display for RomanceOrdinal {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let full_stop = if self.full_stop { "." } else { "" };
+        let indicator = match (crate::plain_mode(), self.gender) {
+            (false, _) => self.indicator(),
+            (true, Gender::Masculine) => 'o',
+            (true, Gender::Feminine) => 'a',
+        };
+        write!(f, "{}{}{}", self.value, full_stop, indicator)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    extern crate std;
+
+    #[test]
+    fn english_suffixes() {
+        let res = [1, 2, 3, 4, 0].map(|n| std::format!("{}", ordinal(n)));
+        assert_eq!(res, ["1ˢᵗ", "2ⁿᵈ", "3ʳᵈ", "4ᵗʰ", "0ᵗʰ"]);
+        let res = [11, 12, 13, 101, 1002].map(|n| std::format!("{}", ordinal(n)));
+        assert_eq!(res, ["11ᵗʰ", "12ᵗʰ", "13ᵗʰ", "101ˢᵗ", "1002ⁿᵈ"]);
+    }
+
+    #[test]
+    fn romance_indicators() {
+        let res = std::format!("{}", RomanceOrdinal::new(12, Gender::Masculine));
+        assert_eq!(res, "12º");
+        let res = RomanceOrdinal::new(21, Gender::Feminine).full_stop();
+        assert_eq!(std::format!("{}", res), "21.ª");
+        assert_eq!(res.indicator(), 'ª');
+    }
+}
@@ -0,0 +1,76 @@
+//! Parallel bulk conversions with [rayon].
+//!
+//! This module requires the `rayon` feature.
+//!
+//! ```
+//! use indexing_fmt::par;
+//!
+//! let ids: Vec<u32> = (1..=3).collect();
+//! assert_eq!(par::superscript_strings(&ids), ["¹", "²", "³"]);
+//!
+//! let log = "x<sup>2</sup>\ny<sub>12</sub>";
+//! assert_eq!(par::from_html_lines(log).unwrap(), "x²\ny₁₂");
+//! ```
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::Display;
+
+use rayon::prelude::*;
+
+use crate::markup::html::{FromHtml, HtmlError};
+use crate::{Subscript, Superscript};
+
+/// Formats all values as superscripts in parallel.
+pub fn superscript_strings<T>(values: &[T]) -> Vec<String>
+where
+    T: Copy + Sync,
+    Superscript<T>: Display,
+{
+    values
+        .par_iter()
+        .map(|v| Superscript(*v).to_string())
+        .collect()
+}
+
+/// Formats all values as subscripts in parallel.
+pub fn subscript_strings<T>(values: &[T]) -> Vec<String>
+where
+    T: Copy + Sync,
+    Subscript<T>: Display,
+{
+    values
+        .par_iter()
+        .map(|v| Subscript(*v).to_string())
+        .collect()
+}
+
+/// Converts HTML scripts as [FromHtml] does, processing the lines of the text in parallel.
+///
+/// Every line is converted on its own, so `<sup>` and `<sub>` elements must not span multiple
+/// lines. The first error in reading order is returned.
+pub fn from_html_lines(text: &str) -> Result<String, HtmlError> {
+    let lines = text
+        .par_split_inclusive('\n')
+        .map(|line| {
+            let mut res = String::with_capacity(line.len());
+            FromHtml::new(line).write_to(&mut res).map(|_| res)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(lines.concat())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn errors_in_reading_order() {
+        let text = "a<sup>1</sup>\nb<sub>2\nc<sup>3</sub>\n";
+        assert_eq!(from_html_lines(text), Err(HtmlError::Unclosed));
+        let values: Vec<i64> = (-500..500).collect();
+        let res = subscript_strings(&values);
+        assert_eq!(res[0], "₋₅₀₀");
+        assert_eq!(res.len(), 1000);
+    }
+}
@@ -0,0 +1,313 @@
+//! Reading script notation back into numbers, with limits for untrusted input.
+//!
+//! The parsers of this module accept [Limits] and stop with a [LimitExceeded] error as soon as
+//! one of them is reached, instead of scanning arbitrarily long input. These are the methods of
+//! [Limits], the free functions and the [FromStr](core::str::FromStr) implementations, which use
+//! [Limits::DEFAULT].
+//!
+//! Other scanners of this crate, such as [cmp_indexed](crate::label::cmp_indexed),
+//! [CollationKey](crate::label::CollationKey) and [equation](crate::chem::equation), do not take
+//! [Limits]. Callers need to bound the length of untrusted input for them, e.g. with
+//! [Limits::check_input].
+//!
+//! ```
+//! use indexing_fmt::parse::*;
+//!
+//! let limits = Limits::default().max_input_len(8);
+//! assert_eq!(limits.check_input("x¹²"), Ok(()));
+//! assert_eq!(limits.check_input("x¹²³⁴⁵⁶⁷⁸⁹"), Err(LimitExceeded::InputLength));
+//! ```
+//!
+//! Integers are parsed with [FromStr](core::str::FromStr) or the functions of this module.
+//!
+//! ```
+//! use indexing_fmt::*;
+//! use indexing_fmt::parse::*;
+//!
+//! let value: Superscript<i32> = "⁻¹²".parse().unwrap();
+//! assert_eq!(value, Superscript(-12));
+//! assert_eq!(from_subscript_str::<u8>("₂₅₆"), Err(ParseError::Overflow));
+//! ```
+
+use crate::chars::split_trailing_digits;
+use crate::{ScriptPosition, Subscript, Superscript, from_subscript_char, from_superscript_char};
+
+/// Upper bounds which are checked while parsing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Limits {
+    max_input_len: usize,
+    max_digits: usize,
+}
+
+impl Limits {
+    /// Allows 4096 bytes of input and 40 digits per number, which is enough for every [u128].
+    pub const DEFAULT: Limits = Limits {
+        max_input_len: 4096,
+        max_digits: 40,
+    };
+
+    /// Sets the maximum length of the input in bytes.
+    pub const fn max_input_len(self, max_input_len: usize) -> Self {
+        Limits {
+            max_input_len,
+            ..self
+        }
+    }
+
+    /// Sets the maximum number of digits of a single number, including leading zeros.
+    pub const fn max_digits(self, max_digits: usize) -> Self {
+        Limits { max_digits, ..self }
+    }
+
+    /// Checks the length of the input before it is scanned.
+    pub fn check_input(&self, text: &str) -> Result<(), LimitExceeded> {
+        match text.len() > self.max_input_len {
+            true => Err(LimitExceeded::InputLength),
+            false => Ok(()),
+        }
+    }
+
+    /// Checks the number of digits which were read so far.
+    pub fn check_digits(&self, digits: usize) -> Result<(), LimitExceeded> {
+        match digits > self.max_digits {
+            true => Err(LimitExceeded::Digits),
+            false => Ok(()),
+        }
+    }
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// The limit which was exceeded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LimitExceeded {
+    /// The input is longer than [Limits::max_input_len].
+    InputLength,
+    /// A number has more digits than [Limits::max_digits].
+    Digits,
+}
+
+impl core::fmt::Display for LimitExceeded {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            LimitExceeded::InputLength => f.write_str("input exceeds the maximum length"),
+            LimitExceeded::Digits => f.write_str("number exceeds the maximum count of digits"),
+        }
+    }
+}
+
+impl core::error::Error for LimitExceeded {}
+
+/// Reasons why a script could not be parsed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ParseError {
+    /// A [Limits] was exceeded.
+    Limit(LimitExceeded),
+    /// The text contains no digits.
+    Empty,
+    /// The character is no digit or sign of the expected script.
+    InvalidCharacter(char),
+    /// The number does not fit into the target type.
+    Overflow,
+}
+
+impl From<LimitExceeded> for ParseError {
+    fn from(limit: LimitExceeded) -> Self {
+        ParseError::Limit(limit)
+    }
+}
+
+impl core::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseError::Limit(limit) => write!(f, "{}", limit),
+            ParseError::Empty => f.write_str("no digits to parse"),
+            ParseError::InvalidCharacter(c) => write!(f, "invalid character {:?}", c),
+            ParseError::Overflow => f.write_str("number does not fit into the target type"),
+        }
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+impl Limits {
+    /// Parses a superscript integer such as `⁻¹²` within these limits.
+    pub fn parse_superscript<T>(&self, text: &str) -> Result<T, ParseError>
+    where
+        T: TryFrom<u128> + TryFrom<i128>,
+    {
+        self.parse_script(text, ScriptPosition::Superscript)
+    }
+
+    /// Parses a subscript integer such as `₋₁₂` within these limits.
+    pub fn parse_subscript<T>(&self, text: &str) -> Result<T, ParseError>
+    where
+        T: TryFrom<u128> + TryFrom<i128>,
+    {
+        self.parse_script(text, ScriptPosition::Subscript)
+    }
+
+    /// Splits a name such as `Ship¹²` into its base and index within these limits.
+    ///
+    /// The index is the trailing run of superscript or subscript digits, including a sign of the
+    /// same script directly in front of it. Whether the run is superscript or subscript is
+    /// determined by its last character.
+    ///
+    /// ```
+    /// # use indexing_fmt::parse::*;
+    /// let limits = Limits::default();
+    /// assert_eq!(limits.parse_indexed_name("Ship¹²"), Ok(("Ship", 12)));
+    /// assert_eq!(limits.parse_indexed_name("Docking-Bay₈₄₀"), Ok(("Docking-Bay", 840)));
+    /// assert_eq!(limits.parse_indexed_name("x⁻¹"), Ok(("x", -1)));
+    /// assert_eq!(limits.parse_indexed_name::<u8>("Ship"), Err(ParseError::Empty));
+    /// ```
+    pub fn parse_indexed_name<'a, T>(&self, text: &'a str) -> Result<(&'a str, T), ParseError>
+    where
+        T: TryFrom<u128> + TryFrom<i128>,
+    {
+        self.check_input(text)?;
+        let (base, _, position) = split_trailing_digits(text);
+        let Some(position) = position else {
+            return Err(ParseError::Empty);
+        };
+        let signs = match position {
+            ScriptPosition::Superscript => ['\u{207a}', '\u{207b}'],
+            ScriptPosition::Subscript => ['\u{208a}', '\u{208b}'],
+        };
+        let base = base.strip_suffix(signs).unwrap_or(base);
+        let index = self.parse_script(&text[base.len()..], position)?;
+        Ok((base, index))
+    }
+
+    fn parse_script<T>(&self, text: &str, position: ScriptPosition) -> Result<T, ParseError>
+    where
+        T: TryFrom<u128> + TryFrom<i128>,
+    {
+        self.check_input(text)?;
+        let (signs, value): (_, fn(char) -> Option<u8>) = match position {
+            ScriptPosition::Superscript => (['\u{207a}', '\u{207b}'], from_superscript_char),
+            ScriptPosition::Subscript => (['\u{208a}', '\u{208b}'], from_subscript_char),
+        };
+        let (negative, digits) = match text.strip_prefix(signs) {
+            Some(digits) => (text.starts_with(signs[1]), digits),
+            None => (false, text),
+        };
+        let mut magnitude = 0u128;
+        let mut count = 0;
+        for c in digits.chars() {
+            let digit = value(c).ok_or(ParseError::InvalidCharacter(c))?;
+            count += 1;
+            self.check_digits(count)?;
+            magnitude = magnitude
+                .checked_mul(10)
+                .and_then(|m| m.checked_add(u128::from(digit)))
+                .ok_or(ParseError::Overflow)?;
+        }
+        if count == 0 {
+            return Err(ParseError::Empty);
+        }
+        let value = match negative {
+            false => T::try_from(magnitude).ok(),
+            true => 0i128
+                .checked_sub_unsigned(magnitude)
+                .and_then(|v| T::try_from(v).ok()),
+        };
+        value.ok_or(ParseError::Overflow)
+    }
+}
+
+/// Parses a superscript integer such as `⁻¹²` within the [Limits::DEFAULT].
+pub fn from_superscript_str<T>(text: &str) -> Result<T, ParseError>
+where
+    T: TryFrom<u128> + TryFrom<i128>,
+{
+    Limits::DEFAULT.parse_superscript(text)
+}
+
+/// Parses a subscript integer such as `₋₁₂` within the [Limits::DEFAULT].
+pub fn from_subscript_str<T>(text: &str) -> Result<T, ParseError>
+where
+    T: TryFrom<u128> + TryFrom<i128>,
+{
+    Limits::DEFAULT.parse_subscript(text)
+}
+
+/// Splits a name such as `Ship¹²` into its base and index within the [Limits::DEFAULT].
+///
+/// See [Limits::parse_indexed_name].
+pub fn parse_indexed_name<T>(text: &str) -> Result<(&str, T), ParseError>
+where
+    T: TryFrom<u128> + TryFrom<i128>,
+{
+    Limits::DEFAULT.parse_indexed_name(text)
+}
+
+macro_rules! impl_from_str(
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl core::str::FromStr for Superscript<$ty> {
+                type Err = ParseError;
+
+                fn from_str(text: &str) -> Result<Self, ParseError> {
+                    from_superscript_str(text).map(Superscript)
+                }
+            }
+
+            impl core::str::FromStr for Subscript<$ty> {
+                type Err = ParseError;
+
+                fn from_str(text: &str) -> Result<Self, ParseError> {
+                    from_subscript_str(text).map(Subscript)
+                }
+            }
+        )*
+    };
+);
+
+impl_from_str!(
+    u128, i128, usize, isize, u64, i64, u32, i32, u16, i16, u8, i8
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn digit_limit() {
+        let limits = Limits::default().max_digits(3);
+        assert_eq!(limits.check_digits(3), Ok(()));
+        assert_eq!(limits.check_digits(4), Err(LimitExceeded::Digits));
+        assert_eq!(Limits::default().check_digits(39), Ok(()));
+    }
+
+    #[test]
+    fn parse_integers() {
+        assert_eq!("₊₀₇".parse(), Ok(Subscript(7u8)));
+        assert_eq!("⁻¹²⁸".parse(), Ok(Superscript(i8::MIN)));
+        assert_eq!(from_superscript_str::<i8>("¹²⁸"), Err(ParseError::Overflow));
+        assert_eq!(from_superscript_str::<u32>("⁻¹"), Err(ParseError::Overflow));
+        assert_eq!(from_superscript_str::<u32>("⁻⁰"), Ok(0));
+        let max = "³⁴⁰²⁸²³⁶⁶⁹²⁰⁹³⁸⁴⁶³⁴⁶³³⁷⁴⁶⁰⁷⁴³¹⁷⁶⁸²¹¹⁴⁵⁵";
+        assert_eq!(from_superscript_str::<u128>(max), Ok(u128::MAX));
+        assert_eq!(
+            from_subscript_str::<i32>("₁2"),
+            Err(ParseError::InvalidCharacter('2'))
+        );
+        assert_eq!(from_subscript_str::<i32>("₋"), Err(ParseError::Empty));
+        let limits = Limits::default().max_digits(2);
+        let error = ParseError::Limit(LimitExceeded::Digits);
+        assert_eq!(limits.parse_subscript::<i32>("₁₂₃"), Err(error));
+    }
+
+    #[test]
+    fn indexed_names() {
+        assert_eq!(parse_indexed_name("x₁²"), Ok(("x₁", 2)));
+        assert_eq!(parse_indexed_name("x₋²"), Ok(("x₋", 2)));
+        assert_eq!(parse_indexed_name("₋₃"), Ok(("", -3)));
+        assert_eq!(parse_indexed_name::<u8>("n₉₉₉"), Err(ParseError::Overflow));
+    }
+}
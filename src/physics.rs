@@ -0,0 +1,452 @@
+//! Notation commonly found in physics.
+//!
+//! ```
+//! use indexing_fmt::physics::*;
+//!
+//! assert_eq!(format!("{}", Particle::new("ν").anti().label("e")), "ν̄ₑ");
+//! ```
+
+use core::fmt::{Display, Write};
+
+use crate::Spacing;
+use crate::chars::{Fallback, subscript_char, superscript_char, write_mapped};
+use crate::math::Order;
+use crate::translit::Unmappable;
+use crate::{FormatSubscript, FormatSuperscript, ScriptPosition, Subscript, Superscript};
+
+/// Displays a particle symbol with its charge and a flavor or state label, e.g. `π⁺` or `K⁰_S`.
+///
+/// Charges up to two are shown by repeating the sign, e.g. `Δ⁺⁺`, larger ones by their magnitude
+/// and sign as in `Ω³⁻`. The label is subscripted if all of its
+/// characters have a subscript form and is otherwise appended as `_label`.
+///
+/// ```
+/// # use indexing_fmt::physics::*;
+/// assert_eq!(format!("{}", Particle::new("μ").charge(-1)), "μ⁻");
+/// assert_eq!(format!("{}", Particle::new("K").charge(0).label("S")), "K⁰_S");
+/// assert_eq!(format!("{}", Particle::new("Δ").charge(2)), "Δ⁺⁺");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Particle<'a> {
+    name: &'a str,
+    charge: Option<i32>,
+    label: Option<&'a str>,
+    anti: bool,
+    spacing: Spacing,
+}
+
+impl<'a> Particle<'a> {
+    pub fn new(name: &'a str) -> Self {
+        Particle {
+            name,
+            charge: None,
+            label: None,
+            anti: false,
+            spacing: Spacing::None,
+        }
+    }
+
+    /// Displays the charge in units of the elementary charge.
+    pub fn charge(self, charge: i32) -> Self {
+        Particle {
+            charge: Some(charge),
+            ..self
+        }
+    }
+
+    /// Adds a subscripted flavor or state label.
+    pub fn label(self, label: &'a str) -> Self {
+        Particle {
+            label: Some(label),
+            ..self
+        }
+    }
+
+    /// Marks the antiparticle with an overbar.
+    pub fn anti(self) -> Self {
+        Particle { anti: true, ..self }
+    }
+
+    /// Inserts a space between the symbol and its charge and label.
+    pub fn spacing(self, spacing: Spacing) -> Self {
+        Particle { spacing, ..self }
+    }
+}
+
+impl Display for Particle<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.anti {
+            // A macron on a single letter and a continuous overline on longer names
+            let bar = match self.name.chars().count() {
+                1 => '\u{0304}',
+                _ => '\u{0305}',
+            };
+            for c in self.name.chars() {
+                f.write_char(c)?;
+                f.write_char(bar)?;
+            }
+        } else {
+            f.write_str(self.name)?;
+        }
+        if self.charge.is_some() || self.label.is_some() {
+            write!(f, "{}", self.spacing)?;
+        }
+        match self.charge {
+            Some(0) => write!(f, "{}", Superscript('0'))?,
+            Some(charge @ -2..=2) => {
+                let sign = Superscript(if charge > 0 { '+' } else { '-' });
+                for _ in 0..charge.unsigned_abs() {
+                    write!(f, "{}", sign)?;
+                }
+            }
+            Some(charge) => write!(f, "{}", crate::chem::Charge(charge))?,
+            None => (),
+        }
+        if let Some(label) = self.label {
+            if label.chars().all(|c| subscript_char(c).is_some()) {
+                write!(f, "{}", Subscript(label))?;
+            } else {
+                write!(f, "_{}", label)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Letters of the orbital angular momentum quantum number `L = 0, 1, 2, …`.
+const ORBITAL_LETTERS: [char; 20] = [
+    'S', 'P', 'D', 'F', 'G', 'H', 'I', 'K', 'L', 'M', 'N', 'O', 'Q', 'R', 'T', 'U', 'V', 'W', 'X',
+    'Y',
+];
+
+/// Displays an atomic term symbol `²ˢ⁺¹L_J` such as `²S₁/₂` or `³P₂`.
+///
+/// Since `J` may be a half-integer, it is given as `2J`. Odd parity is indicated by a
+/// superscript `ᵒ`.
+///
+/// ```
+/// # use indexing_fmt::physics::*;
+/// assert_eq!(format!("{}", TermSymbol::new(2, 0, 1)), "²S₁/₂");
+/// assert_eq!(format!("{}", TermSymbol::new(3, 1, 4)), "³P₂");
+/// assert_eq!(format!("{}", TermSymbol::new(2, 1, 3).odd_parity()), "²Pᵒ₃/₂");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TermSymbol {
+    multiplicity: u32,
+    orbital: u32,
+    twice_j: u32,
+    odd_parity: bool,
+}
+
+impl TermSymbol {
+    /// Creates the term symbol from the multiplicity `2S+1`, `L` and `2J`.
+    pub fn new(multiplicity: u32, orbital: u32, twice_j: u32) -> Self {
+        TermSymbol {
+            multiplicity,
+            orbital,
+            twice_j,
+            odd_parity: false,
+        }
+    }
+
+    pub fn odd_parity(self) -> Self {
+        TermSymbol {
+            odd_parity: true,
+            ..self
+        }
+    }
+}
+
+impl Display for TermSymbol {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.multiplicity.to_superscript())?;
+        match ORBITAL_LETTERS.get(self.orbital as usize) {
+            Some(letter) => f.write_char(*letter)?,
+            None => write!(f, "[{}]", self.orbital)?,
+        }
+        if self.odd_parity {
+            f.write_char('\u{1d52}')?;
+        }
+        if self.twice_j % 2 == 0 {
+            write!(f, "{}", (self.twice_j / 2).to_subscript())
+        } else {
+            write!(f, "{}/{}", self.twice_j.to_subscript(), 2.to_subscript())
+        }
+    }
+}
+
+/// Contents of a [Ket].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum State<'a> {
+    Symbol(&'a str),
+    /// Occupation numbers of the modes `1, 2, …`.
+    Fock(&'a [u32]),
+}
+
+/// Displays a quantum state in Dirac notation, e.g. `|ψ⟩`, `⟨φ|` or `|0₁ 1₂⟩`.
+///
+/// ```
+/// # use indexing_fmt::physics::*;
+/// assert_eq!(format!("{}", Ket::new("ψ")), "|ψ⟩");
+/// assert_eq!(format!("{}", Ket::new("φ").bra()), "⟨φ|");
+/// assert_eq!(format!("{}", Ket::fock(&[0, 1])), "|0₁ 1₂⟩");
+/// assert_eq!(format!("{}", Ket::new("ψ").tensor_power("n")), "|ψ⟩⊗ⁿ");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Ket<'a> {
+    state: State<'a>,
+    bra: bool,
+    tensor_power: Option<Order<'a>>,
+}
+
+impl<'a> Ket<'a> {
+    pub fn new(symbol: &'a str) -> Self {
+        Ket {
+            state: State::Symbol(symbol),
+            bra: false,
+            tensor_power: None,
+        }
+    }
+
+    /// A Fock state with the given occupation numbers, whose modes are subscripted starting at one.
+    pub fn fock(occupations: &'a [u32]) -> Self {
+        Ket {
+            state: State::Fock(occupations),
+            ..Ket::new("")
+        }
+    }
+
+    /// Displays the dual state `⟨…|` instead.
+    pub fn bra(self) -> Self {
+        Ket { bra: true, ..self }
+    }
+
+    /// Appends a tensor power `⊗ⁿ`.
+    pub fn tensor_power(self, power: impl Into<Order<'a>>) -> Self {
+        Ket {
+            tensor_power: Some(power.into()),
+            ..self
+        }
+    }
+}
+
+impl Display for Ket<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let (open, close) = match self.bra {
+            true => ('\u{27e8}', '|'),
+            false => ('|', '\u{27e9}'),
+        };
+        f.write_char(open)?;
+        match self.state {
+            State::Symbol(symbol) => f.write_str(symbol)?,
+            State::Fock(occupations) => {
+                for (mode, n) in occupations.iter().enumerate() {
+                    if mode > 0 {
+                        f.write_char(' ')?;
+                    }
+                    write!(f, "{}{}", n, (mode + 1).to_subscript())?;
+                }
+            }
+        }
+        f.write_char(close)?;
+        match self.tensor_power {
+            Some(Order::Numeric(n)) => write!(f, "\u{2297}{}", n.to_superscript()),
+            Some(Order::Symbolic(s)) => {
+                f.write_char('\u{2297}')?;
+                write_mapped(f, s, superscript_char, Fallback::Keep)
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+/// Displays a tensor with mixed upper and lower indices, e.g. `Tⁱʲₖ`.
+///
+/// The indices are written in the order they are added. Characters without a script form are
+/// kept unchanged. The indices are stored inline, such that a tensor holds at most
+/// [Tensor::CAPACITY] of them and further indices are not written. [Tensor::validate] detects both
+/// beforehand.
+///
+/// ```
+/// # use indexing_fmt::physics::*;
+/// assert_eq!(format!("{}", Tensor::new("T").upper("i").upper("j").lower("k")), "Tⁱʲₖ");
+/// assert_eq!(format!("{}", Tensor::new("Γ").upper("k").lower("ij")), "Γᵏᵢⱼ");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Tensor<'a> {
+    name: &'a str,
+    indices: [Option<(ScriptPosition, &'a str)>; Tensor::CAPACITY],
+    len: usize,
+    overflow: bool,
+}
+
+/// Reasons why a [Tensor] cannot be written as requested.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TensorError {
+    /// A character of an index has no form in its script.
+    Unmappable(char),
+    /// More than [Tensor::CAPACITY] indices were added.
+    TooManyIndices,
+}
+
+impl Display for TensorError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TensorError::Unmappable(c) => write!(f, "{}", Unmappable(*c)),
+            TensorError::TooManyIndices => {
+                write!(f, "more than {} tensor indices", Tensor::CAPACITY)
+            }
+        }
+    }
+}
+
+impl core::error::Error for TensorError {}
+
+impl<'a> Tensor<'a> {
+    /// The maximum number of indices.
+    pub const CAPACITY: usize = 8;
+
+    pub fn new(name: &'a str) -> Self {
+        Tensor {
+            name,
+            indices: [None; Tensor::CAPACITY],
+            len: 0,
+            overflow: false,
+        }
+    }
+
+    fn push(mut self, position: ScriptPosition, index: &'a str) -> Self {
+        match self.indices.get_mut(self.len) {
+            Some(slot) => {
+                *slot = Some((position, index));
+                self.len += 1;
+            }
+            None => self.overflow = true,
+        }
+        self
+    }
+
+    /// Appends a contravariant index as superscript.
+    pub fn upper(self, index: &'a str) -> Self {
+        self.push(ScriptPosition::Superscript, index)
+    }
+
+    /// Appends a covariant index as subscript.
+    pub fn lower(self, index: &'a str) -> Self {
+        self.push(ScriptPosition::Subscript, index)
+    }
+
+    fn indices(&self) -> impl Iterator<Item = &(ScriptPosition, &'a str)> {
+        self.indices[..self.len].iter().flatten()
+    }
+
+    /// Checks that all indices are kept and that all of their characters have a form in their
+    /// script.
+    ///
+    /// Only some Latin and Greek letters have script forms, e.g. there is no superscript `q` and
+    /// no subscript `μ`.
+    ///
+    /// ```
+    /// # use indexing_fmt::physics::*;
+    /// assert!(Tensor::new("g").lower("i").lower("j").validate().is_ok());
+    /// let res = Tensor::new("g").lower("μ").lower("ν").validate();
+    /// assert_eq!(res, Err(TensorError::Unmappable('μ')));
+    /// ```
+    pub fn validate(self) -> Result<Self, TensorError> {
+        if self.overflow {
+            return Err(TensorError::TooManyIndices);
+        }
+        for (position, index) in self.indices() {
+            let strict = match position {
+                ScriptPosition::Superscript => Superscript(*index).strict().map(|_| ()),
+                ScriptPosition::Subscript => Subscript(*index).strict().map(|_| ()),
+            };
+            strict.map_err(|Unmappable(c)| TensorError::Unmappable(c))?;
+        }
+        Ok(self)
+    }
+}
+
+impl Display for Tensor<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.name)?;
+        for (position, index) in self.indices() {
+            match position {
+                ScriptPosition::Superscript => write!(f, "{}", Superscript(*index))?,
+                ScriptPosition::Subscript => write!(f, "{}", Subscript(*index))?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    extern crate std;
+
+    #[test]
+    fn particle_antiparticles() {
+        let res = std::format!("{}", Particle::new("p").anti().charge(-1));
+        assert_eq!(res, "p̄⁻");
+
+        let res = std::format!("{}", Particle::new("ν").anti().label("μ"));
+        assert_eq!(res, "ν̄_μ");
+
+        let res = std::format!("{}", Particle::new("Ds").anti().charge(-1));
+        assert_eq!(res, "D̅s̅⁻");
+    }
+
+    #[test]
+    fn particle_spacing() {
+        let res = Particle::new("W").charge(1).spacing(Spacing::Hair);
+        assert_eq!(std::format!("{}", res), "W\u{200a}⁺");
+        let res = Particle::new("γ").spacing(Spacing::Thin);
+        assert_eq!(std::format!("{}", res), "γ");
+    }
+
+    #[test]
+    fn particle_large_charges() {
+        assert_eq!(std::format!("{}", Particle::new("Δ").charge(-2)), "Δ⁻⁻");
+        assert_eq!(std::format!("{}", Particle::new("Ω").charge(-3)), "Ω³⁻");
+        assert_eq!(std::format!("{}", Particle::new("X").charge(3)), "X³⁺");
+        let res = std::format!("{}", Particle::new("X").charge(i32::MIN));
+        assert_eq!(res, "X²¹⁴⁷⁴⁸³⁶⁴⁸⁻");
+    }
+
+    #[test]
+    fn fock_bra_with_power() {
+        let res = std::format!("{}", Ket::fock(&[2, 0, 13]).bra().tensor_power(2));
+        assert_eq!(res, "⟨2₁ 0₂ 13₃|⊗²");
+        assert_eq!(std::format!("{}", Ket::fock(&[])), "|⟩");
+    }
+
+    #[test]
+    fn term_symbol_letters() {
+        assert_eq!(std::format!("{}", TermSymbol::new(1, 2, 4)), "¹D₂");
+        assert_eq!(std::format!("{}", TermSymbol::new(4, 3, 9)), "⁴F₉/₂");
+        assert_eq!(std::format!("{}", TermSymbol::new(1, 7, 14)), "¹K₇");
+        assert_eq!(std::format!("{}", TermSymbol::new(1, 25, 50)), "¹[25]₂₅");
+    }
+
+    #[test]
+    fn tensor_indices() {
+        let riemann = Tensor::new("R").upper("ρ").lower("σ").lower("μν");
+        assert_eq!(riemann.validate(), Err(TensorError::Unmappable('ρ')));
+        let res = Tensor::new("R").upper("i").lower("jkl").validate().unwrap();
+        assert_eq!(std::format!("{}", res), "Rⁱⱼₖₗ");
+        let res = Tensor::new("R").upper("β").lower("γρ").validate().unwrap();
+        assert_eq!(std::format!("{}", res), "Rᵝᵧᵨ");
+        let res = Tensor::new("ε").lower("ijk").validate().unwrap();
+        assert_eq!(std::format!("{}", res), "εᵢⱼₖ");
+        let res = Tensor::new("δ").upper("q").validate();
+        assert_eq!(res, Err(TensorError::Unmappable('q')));
+        assert_eq!(std::format!("{}", Tensor::new("x")), "x");
+        let tensor = (0..9).fold(Tensor::new("T"), |t, _| t.lower("i"));
+        assert_eq!(tensor.validate(), Err(TensorError::TooManyIndices));
+        assert_eq!(
+            std::format!("{}", tensor),
+            std::format!("T{}", "ᵢ".repeat(8))
+        );
+    }
+}
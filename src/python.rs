@@ -0,0 +1,88 @@
+//! Python bindings for the formatting routines of this crate.
+//!
+//! The module is built with [maturin](https://www.maturin.rs/) by enabling the `python` feature.
+//! maturin builds the crate as `cdylib` on its own, such that `Cargo.toml` does not declare this
+//! crate type, which would require a panic handler in `no_std` builds.
+//!
+//! ```python
+//! import indexing_fmt
+//!
+//! assert indexing_fmt.superscript(12) == "¹²"
+//! assert indexing_fmt.subscript_digits("H2O") == "H₂O"
+//! assert indexing_fmt.formula("Ca(OH)2") == "Ca(OH)₂"
+//! ```
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use std::string::{String, ToString};
+
+use crate::translit::{SubscriptDigits, SuperscriptDigits};
+use crate::{FormatSubscript, FormatSuperscript};
+
+/// Formats an integer as superscript.
+#[pyfunction]
+fn superscript(value: i128) -> String {
+    std::format!("{}", value.to_superscript())
+}
+
+/// Formats an integer as subscript.
+#[pyfunction]
+fn subscript(value: i128) -> String {
+    std::format!("{}", value.to_subscript())
+}
+
+/// Replaces all digits and signs of a string by their superscript counterparts.
+#[pyfunction]
+fn superscript_digits(text: &str) -> String {
+    std::format!("{}", SuperscriptDigits(text))
+}
+
+/// Replaces all digits and signs of a string by their subscript counterparts.
+#[pyfunction]
+fn subscript_digits(text: &str) -> String {
+    std::format!("{}", SubscriptDigits(text))
+}
+
+/// Parses a superscript integer, raising `ValueError` for invalid input.
+#[pyfunction]
+fn parse_superscript(text: &str) -> PyResult<i128> {
+    crate::parse::from_superscript_str(text).map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+/// Parses a subscript integer, raising `ValueError` for invalid input.
+#[pyfunction]
+fn parse_subscript(text: &str) -> PyResult<i128> {
+    crate::parse::from_subscript_str(text).map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+/// Subscripts the counts of a plain chemical formula like `"H2O"`.
+#[pyfunction]
+fn formula(text: &str) -> String {
+    std::format!("{}", crate::chem::formula(text))
+}
+
+#[pymodule]
+fn indexing_fmt(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(superscript, m)?)?;
+    m.add_function(wrap_pyfunction!(subscript, m)?)?;
+    m.add_function(wrap_pyfunction!(superscript_digits, m)?)?;
+    m.add_function(wrap_pyfunction!(subscript_digits, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_superscript, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_subscript, m)?)?;
+    m.add_function(wrap_pyfunction!(formula, m)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn exported_functions() {
+        assert_eq!(superscript(-12), "⁻¹²");
+        assert_eq!(subscript(7), "₇");
+        assert_eq!(superscript_digits("x2+1"), "x²⁺¹");
+        assert_eq!(subscript_digits("H2O"), "H₂O");
+        assert_eq!(formula("CuSO4·5H2O"), "CuSO₄·5H₂O");
+    }
+}
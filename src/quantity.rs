@@ -0,0 +1,202 @@
+//! Superscript exponents for the dimensions and quantities of [uom].
+//!
+//! This module requires the `uom` feature.
+//!
+//! ```
+//! use indexing_fmt::quantity::*;
+//! use uom::si::acceleration::meter_per_second_squared;
+//! use uom::si::f64::Acceleration;
+//!
+//! let g = Acceleration::new::<meter_per_second_squared>(9.81);
+//! assert_eq!(format!("{}", si_base_units(&g)), "9.81 m·s⁻²");
+//! assert_eq!(format!("{}", SiDimension::of(&g).solidus()), "m/s²");
+//! ```
+
+use core::fmt::Display;
+use core::marker::PhantomData;
+
+use uom::si::{Dimension, Quantity, Units as SiUnits};
+use uom::typenum::Integer;
+
+use crate::Spacing;
+use crate::sci::Units;
+
+/// The symbols of the SI base units in the order of the base quantities of [Dimension].
+const BASE_UNITS: [&str; 7] = ["m", "kg", "s", "A", "K", "mol", "cd"];
+
+/// Displays a dimension of the International System of Quantities in SI base units like
+/// `kg·m²·s⁻²`.
+///
+/// The units are written with [Units], such that a dimension of one is not displayed at all.
+pub struct SiDimension<D: ?Sized> {
+    separator: Spacing,
+    solidus: bool,
+    dimension: PhantomData<D>,
+}
+
+impl<D: ?Sized> Clone for SiDimension<D> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<D: ?Sized> Copy for SiDimension<D> {}
+
+impl<D: Dimension + ?Sized> Default for SiDimension<D> {
+    fn default() -> Self {
+        SiDimension {
+            separator: Spacing::Custom('\u{b7}'),
+            solidus: false,
+            dimension: PhantomData,
+        }
+    }
+}
+
+impl<D: Dimension + ?Sized> SiDimension<D> {
+    pub fn new() -> Self {
+        SiDimension::default()
+    }
+
+    /// The dimension of a quantity.
+    pub fn of<U, V>(_: &Quantity<D, U, V>) -> Self
+    where
+        U: SiUnits<V> + ?Sized,
+        V: uom::num::Num + uom::Conversion<V>,
+    {
+        SiDimension::default()
+    }
+
+    /// The base units with the exponents of the dimension.
+    ///
+    /// ```
+    /// # use indexing_fmt::quantity::*;
+    /// let force = SiDimension::<uom::si::force::Dimension>::new();
+    /// assert_eq!(force.exponents()[..3], [("m", 1), ("kg", 1), ("s", -2)]);
+    /// ```
+    pub fn exponents(&self) -> [(&'static str, i32); 7] {
+        let exponents = [
+            D::L::I32,
+            D::M::I32,
+            D::T::I32,
+            D::I::I32,
+            D::Th::I32,
+            D::N::I32,
+            D::J::I32,
+        ];
+        let mut units = [("", 0); 7];
+        for (unit, (symbol, exponent)) in units.iter_mut().zip(BASE_UNITS.iter().zip(exponents)) {
+            *unit = (*symbol, exponent);
+        }
+        units
+    }
+
+    /// See [Units::separator].
+    pub fn separator(self, separator: Spacing) -> Self {
+        SiDimension { separator, ..self }
+    }
+
+    /// See [Units::solidus].
+    pub fn solidus(self) -> Self {
+        SiDimension {
+            solidus: true,
+            ..self
+        }
+    }
+}
+
+impl<D: Dimension + ?Sized> Display for SiDimension<D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let exponents = self.exponents();
+        let units = Units::new(&exponents).separator(self.separator);
+        match self.solidus {
+            true => write!(f, "{}", units.solidus()),
+            false => write!(f, "{}", units),
+        }
+    }
+}
+
+/// Displays a quantity as its value in SI base units followed by the units like `9.81 m·s⁻²`.
+///
+/// The units are omitted for quantities of dimension one.
+pub struct SiQuantity<'a, D, U, V>
+where
+    D: Dimension + ?Sized,
+    U: SiUnits<V> + ?Sized,
+    V: uom::num::Num + uom::Conversion<V>,
+{
+    quantity: &'a Quantity<D, U, V>,
+    dimension: SiDimension<D>,
+}
+
+/// Starts a [SiQuantity].
+pub fn si_base_units<D, U, V>(quantity: &Quantity<D, U, V>) -> SiQuantity<'_, D, U, V>
+where
+    D: Dimension + ?Sized,
+    U: SiUnits<V> + ?Sized,
+    V: uom::num::Num + uom::Conversion<V>,
+{
+    SiQuantity {
+        quantity,
+        dimension: SiDimension::of(quantity),
+    }
+}
+
+impl<D, U, V> SiQuantity<'_, D, U, V>
+where
+    D: Dimension + ?Sized,
+    U: SiUnits<V> + ?Sized,
+    V: uom::num::Num + uom::Conversion<V>,
+{
+    /// See [Units::separator].
+    pub fn separator(self, separator: Spacing) -> Self {
+        SiQuantity {
+            dimension: self.dimension.separator(separator),
+            ..self
+        }
+    }
+
+    /// See [Units::solidus].
+    pub fn solidus(self) -> Self {
+        SiQuantity {
+            dimension: self.dimension.solidus(),
+            ..self
+        }
+    }
+}
+
+impl<D, U, V> Display for SiQuantity<'_, D, U, V>
+where
+    D: Dimension + ?Sized,
+    U: SiUnits<V> + ?Sized,
+    V: uom::num::Num + uom::Conversion<V> + Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.quantity.value)?;
+        match self.dimension.exponents().iter().all(|(_, e)| *e == 0) {
+            true => Ok(()),
+            false => write!(f, " {}", self.dimension),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    extern crate std;
+
+    use uom::si::f64::{Energy, Ratio, ThermalConductivity};
+    use uom::si::{energy::kilojoule, ratio::percent, thermal_conductivity::watt_per_meter_kelvin};
+
+    #[test]
+    fn base_units() {
+        let energy = Energy::new::<kilojoule>(1.5);
+        assert_eq!(std::format!("{}", si_base_units(&energy)), "1500 m²·kg·s⁻²");
+        let res = si_base_units(&energy).separator(Spacing::Thin).solidus();
+        assert_eq!(std::format!("{}", res), "1500 m²\u{2009}kg/s²");
+        let conductivity = ThermalConductivity::new::<watt_per_meter_kelvin>(0.6);
+        let res = SiDimension::of(&conductivity).solidus();
+        assert_eq!(std::format!("{}", res), "m·kg/(s³·K)");
+        let ratio = Ratio::new::<percent>(50.0);
+        assert_eq!(std::format!("{}", si_base_units(&ratio)), "0.5");
+    }
+}
@@ -0,0 +1,309 @@
+//! Integers in other bases.
+//!
+//! ```
+//! use indexing_fmt::radix::*;
+//!
+//! assert_eq!(format!("{}", radix_suffix(255, 16)), "FF₁₆");
+//! assert_eq!(format!("{}", radix_suffix(10u8, 2)), "1010₂");
+//! assert_eq!(format!("x{}", 255.to_superscript_radix(16)), "xᶠᶠ");
+//! ```
+
+use core::fmt::Write;
+
+use crate::{Fallback, FormatSubscript, ScriptInteger, ScriptPosition};
+
+/// Writes the digits of `magnitude` in the given radix, most significant first.
+///
+/// The value of each digit is converted to a character by `digit`. With a grouping of `(size,
+/// separator)`, the separator is written between groups of `size` digits counted from the least
+/// significant digit.
+pub(crate) fn write_digits(
+    f: &mut impl Write,
+    mut magnitude: u128,
+    radix: u32,
+    grouping: Option<(usize, char)>,
+    digit: impl Fn(u8) -> char,
+) -> core::fmt::Result {
+    // Enough for the binary representation of every u128
+    let mut digits = [0u8; 128];
+    let mut len = 0;
+    loop {
+        digits[len] = (magnitude % radix as u128) as u8;
+        magnitude /= radix as u128;
+        len += 1;
+        if magnitude == 0 {
+            break;
+        }
+    }
+    for n in (0..len).rev() {
+        f.write_char(digit(digits[n]))?;
+        match grouping {
+            Some((size, separator)) if n > 0 && size > 0 && n % size == 0 => {
+                f.write_char(separator)?
+            }
+            _ => (),
+        }
+    }
+    Ok(())
+}
+
+/// Displays an integer in some radix followed by the radix as subscript, e.g. `FF₁₆` or `1010₂`.
+///
+/// Digits above nine are written as uppercase letters unless [RadixSuffix::lowercase] is used.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RadixSuffix {
+    negative: bool,
+    magnitude: u128,
+    radix: u32,
+    uppercase: bool,
+    grouping: Option<(usize, char)>,
+}
+
+/// See [RadixSuffix].
+///
+/// # Panics
+///
+/// Panics if the radix is not in the range `2..=36`.
+pub fn radix_suffix(value: impl Into<i128>, radix: u32) -> RadixSuffix {
+    RadixSuffix::new(value, radix)
+}
+
+impl RadixSuffix {
+    /// See [radix_suffix].
+    pub fn new(value: impl Into<i128>, radix: u32) -> Self {
+        assert!(
+            (2..=36).contains(&radix),
+            "radix must be in the range 2..=36"
+        );
+        let value = value.into();
+        RadixSuffix {
+            negative: value < 0,
+            magnitude: value.unsigned_abs(),
+            radix,
+            uppercase: true,
+            grouping: None,
+        }
+    }
+
+    /// Writes digits above nine as lowercase letters.
+    pub fn lowercase(self) -> Self {
+        RadixSuffix {
+            uppercase: false,
+            ..self
+        }
+    }
+
+    /// Separates groups of `size` digits, e.g. nibbles or bytes of binary numbers.
+    ///
+    /// ```
+    /// # use indexing_fmt::radix::*;
+    /// let res = radix_suffix(0x1f, 2).group(4, '\u{2009}');
+    /// assert_eq!(format!("{}", res), "1\u{2009}1111₂");
+    /// assert_eq!(format!("{}", radix_suffix(0xdeadbeefu32, 16).group(4, '_')), "DEAD_BEEF₁₆");
+    /// ```
+    pub fn group(self, size: usize, separator: char) -> Self {
+        RadixSuffix {
+            grouping: Some((size, separator)),
+            ..self
+        }
+    }
+}
+
+impl core::fmt::Display for RadixSuffix {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.negative {
+            f.write_char('-')?;
+        }
+        let a = if self.uppercase { b'A' } else { b'a' };
+        write_digits(f, self.magnitude, self.radix, self.grouping, |d| match d {
+            0..10 => (b'0' + d) as char,
+            _ => (a + d - 10) as char,
+        })?;
+        write!(f, "{}", self.radix.to_subscript())
+    }
+}
+
+/// Displays an integer with script digits in some radix.
+///
+/// Digits above nine are written as the script forms of lowercase letters. Superscripts exist
+/// for all letters but `q`, while subscripts are missing for many letters such as `b`, `c` and
+/// `d`. Letters without script form are kept on the baseline unless another [Fallback] is chosen.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ScriptRadix {
+    negative: bool,
+    magnitude: u128,
+    position: ScriptPosition,
+    radix: u32,
+    uppercase: bool,
+    fallback: Fallback,
+    grouping: Option<(usize, char)>,
+}
+
+impl ScriptRadix {
+    fn new(negative: bool, magnitude: u128, position: ScriptPosition, radix: u32) -> Self {
+        assert!(
+            (2..=36).contains(&radix),
+            "radix must be in the range 2..=36"
+        );
+        ScriptRadix {
+            negative,
+            magnitude,
+            position,
+            radix,
+            uppercase: false,
+            fallback: Fallback::Keep,
+            grouping: None,
+        }
+    }
+
+    /// Writes digits above nine as uppercase letters.
+    ///
+    /// ```
+    /// # use indexing_fmt::radix::*;
+    /// assert_eq!(format!("{}", 0xab.to_superscript_radix(16).uppercase()), "ᴬᴮ");
+    /// ```
+    pub fn uppercase(self) -> Self {
+        ScriptRadix {
+            uppercase: true,
+            ..self
+        }
+    }
+
+    /// Determines how letter digits without script form are written.
+    ///
+    /// ```
+    /// # use indexing_fmt::radix::*;
+    /// use indexing_fmt::Fallback;
+    ///
+    /// assert_eq!(format!("{}", 0xbe.to_subscript_radix(16)), "bₑ");
+    /// let res = 0xbe.to_subscript_radix(16).fallback(Fallback::Replace('?'));
+    /// assert_eq!(format!("{}", res), "?ₑ");
+    /// ```
+    pub fn fallback(self, fallback: Fallback) -> Self {
+        ScriptRadix { fallback, ..self }
+    }
+
+    /// Separates groups of `size` digits like [RadixSuffix::group]. The separator is written
+    /// unchanged.
+    ///
+    /// ```
+    /// # use indexing_fmt::radix::*;
+    /// assert_eq!(format!("{}", 0xa5.to_superscript_radix(2).group(4, ' ')), "¹⁰¹⁰ ⁰¹⁰¹");
+    /// ```
+    pub fn group(self, size: usize, separator: char) -> Self {
+        ScriptRadix {
+            grouping: Some((size, separator)),
+            ..self
+        }
+    }
+}
+
+impl core::fmt::Display for ScriptRadix {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        ScriptInteger {
+            negative: self.negative,
+            magnitude: self.magnitude,
+            position: self.position,
+            radix: self.radix,
+            uppercase: self.uppercase,
+            fallback: self.fallback,
+            grouping: self.grouping,
+        }
+        .write(f)
+    }
+}
+
+/// Responsible for converting to scripts in radixes other than ten.
+///
+/// Negative values are written with a minus sign followed by the magnitude.
+///
+/// # Panics
+///
+/// The methods panic if the radix is not in the range `2..=36`.
+pub trait FormatRadix {
+    fn to_superscript_radix(&self, radix: u32) -> ScriptRadix;
+
+    fn to_subscript_radix(&self, radix: u32) -> ScriptRadix;
+}
+
+macro_rules! impl_format_radix(
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl FormatRadix for $ty {
+                fn to_superscript_radix(&self, radix: u32) -> ScriptRadix {
+                    let magnitude = self.unsigned_abs() as u128;
+                    ScriptRadix::new(*self < 0, magnitude, ScriptPosition::Superscript, radix)
+                }
+
+                fn to_subscript_radix(&self, radix: u32) -> ScriptRadix {
+                    let magnitude = self.unsigned_abs() as u128;
+                    ScriptRadix::new(*self < 0, magnitude, ScriptPosition::Subscript, radix)
+                }
+            }
+        )*
+    };
+);
+
+impl_format_radix!(i128, isize, i64, i32, i16, i8);
+
+macro_rules! impl_format_radix_unsigned(
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl FormatRadix for $ty {
+                fn to_superscript_radix(&self, radix: u32) -> ScriptRadix {
+                    ScriptRadix::new(false, *self as u128, ScriptPosition::Superscript, radix)
+                }
+
+                fn to_subscript_radix(&self, radix: u32) -> ScriptRadix {
+                    ScriptRadix::new(false, *self as u128, ScriptPosition::Subscript, radix)
+                }
+            }
+        )*
+    };
+);
+
+impl_format_radix_unsigned!(u128, usize, u64, u32, u16, u8);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Superscript;
+    extern crate std;
+
+    #[test]
+    fn extreme_values() {
+        let res = std::format!("{}", radix_suffix(i128::MIN, 36).lowercase());
+        assert_eq!(res, "-7ksyyizzkutudzbv8aqztecjk₃₆");
+        assert_eq!(std::format!("{}", radix_suffix(0, 8)), "0₈");
+        assert_eq!(std::format!("{}", radix_suffix(-5i8, 2)), "-101₂");
+        assert_eq!(std::format!("{}", radix_suffix(0, 2).group(4, ' ')), "0₂");
+        let res = radix_suffix(u64::MAX, 2).group(8, ' ');
+        assert_eq!(std::format!("{}", res).matches(' ').count(), 7);
+    }
+
+    #[test]
+    fn script_radix() {
+        let res = std::format!("{}", (-35).to_subscript_radix(36));
+        assert_eq!(res, "₋z");
+        let res = std::format!("{}", u128::MAX.to_superscript_radix(2));
+        assert_eq!(res.chars().count(), 128);
+        assert_eq!(std::format!("{:+04}", 5u8.to_superscript_radix(2)), "⁺¹⁰¹");
+        assert_eq!(std::format!("{:#}", 26.to_subscript_radix(36)), "_q");
+    }
+
+    #[test]
+    fn grouped_script_radix() {
+        let binary = std::format!("{:b}", Superscript(0x2f5u16));
+        assert_eq!(binary, "¹⁰¹¹¹¹⁰¹⁰¹");
+        let res = std::format!("{}", 0x2f5u16.to_superscript_radix(2).group(4, '\u{2009}'));
+        assert_eq!(res.replace('\u{2009}', ""), binary);
+        assert_eq!(res, "¹⁰\u{2009}¹¹¹¹\u{2009}⁰¹⁰¹");
+        let res = 0xdeadbeefu32.to_superscript_radix(16).group(4, '_');
+        assert_eq!(std::format!("[{:>9}]", res), "[ᵈᵉᵃᵈ_ᵇᵉᵉᶠ]");
+        assert_eq!(std::format!("[{:>10}]", res), "[ ᵈᵉᵃᵈ_ᵇᵉᵉᶠ]");
+        assert_eq!(
+            std::format!("{:#}", 0xffu8.to_subscript_radix(2).group(4, '_')),
+            "_1111_1111"
+        );
+    }
+}
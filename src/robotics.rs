@@ -0,0 +1,157 @@
+//! Frame-annotated quantities as used in robotics and state estimation.
+//!
+//! ```
+//! use indexing_fmt::robotics::*;
+//!
+//! assert_eq!(format!("{}", Framed::new("p").frame("B")), "ᴮp");
+//! assert_eq!(format!("{}", Framed::new("T").frame("B").source("W")), "ᴮT_W");
+//! ```
+
+use core::fmt::{Display, Write};
+
+use crate::chars::{subscript_char, superscript_char};
+
+/// Placement of the frame labels around the symbol.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum FrameConvention {
+    /// The frame precedes the symbol as superscript, e.g. `ᴮp` and `ᴮT_W`.
+    #[default]
+    Leading,
+    /// The frame follows the symbol as superscript, e.g. `pᴮ` and `Tᴮ_W`.
+    Trailing,
+    /// All frames are appended as subscript, e.g. `v_W` and `T_BW`.
+    Subscript,
+}
+
+/// A symbol with the frame it is expressed in and, for transformations, the source frame.
+///
+/// Frame labels are written as scripts if all of their characters have a script form and are
+/// otherwise written as `^label` or `_label`. Since there are no uppercase subscript letters,
+/// subscripted frames usually take the second form.
+///
+/// ```
+/// # use indexing_fmt::robotics::*;
+/// let v = Framed::new("v").frame("W").convention(FrameConvention::Subscript);
+/// assert_eq!(format!("{v}"), "v_W");
+///
+/// let t = Framed::new("T").frame("B").source("W").convention(FrameConvention::Trailing);
+/// assert_eq!(format!("{t}"), "Tᴮ_W");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Framed<'a> {
+    symbol: &'a str,
+    frame: Option<&'a str>,
+    source: Option<&'a str>,
+    convention: FrameConvention,
+}
+
+impl<'a> Framed<'a> {
+    pub fn new(symbol: &'a str) -> Self {
+        Framed {
+            symbol,
+            frame: None,
+            source: None,
+            convention: FrameConvention::Leading,
+        }
+    }
+
+    /// The frame in which the quantity is expressed or, for transformations, the target frame.
+    pub fn frame(self, frame: &'a str) -> Self {
+        Framed {
+            frame: Some(frame),
+            ..self
+        }
+    }
+
+    /// The source frame of a transformation.
+    pub fn source(self, source: &'a str) -> Self {
+        Framed {
+            source: Some(source),
+            ..self
+        }
+    }
+
+    pub fn convention(self, convention: FrameConvention) -> Self {
+        Framed { convention, ..self }
+    }
+}
+
+/// Writes the label as script or, if not all characters can be mapped, after the marker.
+fn write_label(
+    f: &mut impl Write,
+    label: &str,
+    map: fn(char) -> Option<char>,
+    marker: char,
+) -> core::fmt::Result {
+    if label.chars().all(|c| map(c).is_some()) {
+        label
+            .chars()
+            .filter_map(map)
+            .try_for_each(|c| f.write_char(c))
+    } else {
+        f.write_char(marker)?;
+        f.write_str(label)
+    }
+}
+
+impl Display for Framed<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let superscript = |f: &mut core::fmt::Formatter<'_>, label: Option<&str>| match label {
+            Some(label) => write_label(f, label, superscript_char, '^'),
+            None => Ok(()),
+        };
+        match self.convention {
+            FrameConvention::Leading => {
+                superscript(f, self.frame)?;
+                f.write_str(self.symbol)?;
+            }
+            FrameConvention::Trailing => {
+                f.write_str(self.symbol)?;
+                superscript(f, self.frame)?;
+            }
+            FrameConvention::Subscript => {
+                f.write_str(self.symbol)?;
+                let labels = || self.frame.into_iter().chain(self.source);
+                if labels()
+                    .flat_map(str::chars)
+                    .all(|c| subscript_char(c).is_some())
+                {
+                    for c in labels().flat_map(str::chars).filter_map(subscript_char) {
+                        f.write_char(c)?;
+                    }
+                } else if labels().next().is_some() {
+                    f.write_char('_')?;
+                    labels().try_for_each(|label| f.write_str(label))?;
+                }
+            }
+        }
+        match (self.convention, self.source) {
+            (FrameConvention::Subscript, _) | (_, None) => Ok(()),
+            (_, Some(source)) => write_label(f, source, subscript_char, '_'),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    extern crate std;
+
+    #[test]
+    fn subscript_convention() {
+        let t = Framed::new("T").frame("B").source("W");
+        let res = std::format!("{}", t.convention(FrameConvention::Subscript));
+        assert_eq!(res, "T_BW");
+        let res = Framed::new("x")
+            .frame("e")
+            .convention(FrameConvention::Subscript);
+        assert_eq!(std::format!("{}", res), "xₑ");
+        let res = Framed::new("q").frame("IMU").source("cam");
+        assert_eq!(std::format!("{}", res), "ᴵᴹᵁq_cam");
+        let res = Framed::new("p")
+            .frame("C")
+            .convention(FrameConvention::Trailing);
+        assert_eq!(std::format!("{}", res), "p^C");
+        assert_eq!(std::format!("{}", Framed::new("x")), "x");
+    }
+}
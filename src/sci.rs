@@ -0,0 +1,1140 @@
+//! Scientific notation with superscript exponents.
+//!
+//! ```
+//! use indexing_fmt::sci::*;
+//!
+//! assert_eq!(format!("{}", sci(0.00042)), "4.2×10⁻⁴");
+//! assert_eq!(format!("{}", Units::new(&[("m", 1), ("s", -1)])), "m·s⁻¹");
+//! ```
+
+use core::fmt::Write;
+use core::time::Duration;
+
+use crate::decimal::{Decimal, Kind};
+use crate::{FormatSuperscript, Spacing};
+
+/// Rendering of NaN and infinite values.
+///
+/// There are no superscript forms of these values, so they are always written on the baseline
+/// without the `×10ⁿ` part.
+///
+/// ```
+/// # use indexing_fmt::sci::*;
+/// assert_eq!(format!("{}", sci(f64::NEG_INFINITY)), "-inf");
+/// assert_eq!(format!("{}", sci(f64::INFINITY).non_finite(NonFinite::Symbol)), "∞");
+/// assert_eq!(format!("{}", sci(f64::INFINITY).non_finite(NonFinite::SignedSymbol)), "+∞");
+/// assert_eq!(format!("{}", sci(f64::NAN).non_finite(NonFinite::Symbol)), "NaN");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum NonFinite {
+    /// `NaN`, `inf` and `-inf`.
+    #[default]
+    Ascii,
+    /// `NaN`, `∞` and `-∞`.
+    Symbol,
+    /// `NaN`, `+∞` and `-∞`.
+    SignedSymbol,
+    /// Formatting fails with [core::fmt::Error].
+    Error,
+}
+
+/// Rounding of the last significant figure.
+///
+/// The modes act on the magnitude, so negative values are rounded symmetrically.
+///
+/// ```
+/// # use indexing_fmt::sci::*;
+/// let value = sci(2.25).significant_figures(2);
+/// assert_eq!(format!("{}", value), "2.2×10⁰");
+/// assert_eq!(format!("{}", value.rounding(Rounding::HalfUp)), "2.3×10⁰");
+/// assert_eq!(format!("{}", sci(-2.29).significant_figures(2).rounding(Rounding::TowardZero)), "-2.2×10⁰");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Ties are rounded to the even digit, also known as banker's rounding.
+    #[default]
+    HalfEven,
+    /// Ties are rounded away from zero.
+    HalfUp,
+    /// The dropped digits are truncated.
+    TowardZero,
+}
+
+/// Formatting options shared by the types of this module.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Options {
+    significant_figures: Option<usize>,
+    rounding: Rounding,
+    decimal_separator: char,
+    multiplication_sign: char,
+    non_finite: NonFinite,
+    engineering: bool,
+}
+
+impl Options {
+    const DEFAULT: Options = Options {
+        significant_figures: None,
+        rounding: Rounding::HalfEven,
+        decimal_separator: '.',
+        multiplication_sign: '\u{d7}',
+        non_finite: NonFinite::Ascii,
+        engineering: false,
+    };
+
+    /// The sign which is written in front of the value.
+    fn sign(&self, value: &Decimal) -> &'static str {
+        let negative = match value.kind {
+            Kind::Nan => return "",
+            Kind::Infinite if self.non_finite == NonFinite::SignedSymbol && !value.negative => {
+                return "+";
+            }
+            Kind::Infinite => value.negative,
+            Kind::Finite => value.negative && !value.is_zero(),
+        };
+        if negative { "-" } else { "" }
+    }
+
+    /// The text of NaN and infinite values without sign.
+    fn non_finite_text(&self, value: &Decimal) -> Result<Option<&'static str>, core::fmt::Error> {
+        match (value.kind, self.non_finite) {
+            (Kind::Finite, _) => Ok(None),
+            (_, NonFinite::Error) => Err(core::fmt::Error),
+            (Kind::Nan, _) => Ok(Some("NaN")),
+            (Kind::Infinite, NonFinite::Ascii) => Ok(Some("inf")),
+            (Kind::Infinite, _) => Ok(Some("\u{221e}")),
+        }
+    }
+
+    /// Writes the mantissa without sign and returns the exponent of the power of ten.
+    fn write_mantissa(&self, f: &mut impl Write, value: &Decimal) -> Result<i32, core::fmt::Error> {
+        let exponent = value.exponent.checked_sub(value.exponent.rem_euclid(3));
+        let Some(exponent) = exponent.filter(|_| self.engineering && !value.is_zero()) else {
+            value.write_mantissa(f, self.decimal_separator)?;
+            return Ok(value.exponent);
+        };
+        value
+            .shifted(-exponent)
+            .write_fixed(f, self.decimal_separator)?;
+        Ok(exponent)
+    }
+
+    /// Applies all rounding options.
+    fn apply(&self, value: Decimal) -> Decimal {
+        match self.significant_figures {
+            Some(n) => value.rounded(n, self.rounding),
+            None => value,
+        }
+    }
+}
+
+/// Displays a number as `mantissa×10ⁿ`.
+///
+/// See the [module](self) level documentation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SciNotation {
+    value: Decimal,
+    options: Options,
+}
+
+/// Formats a value in scientific notation.
+///
+/// ```
+/// # use indexing_fmt::sci::*;
+/// assert_eq!(format!("{}", sci(-1234.5)), "-1.2345×10³");
+/// ```
+pub fn sci(value: f64) -> SciNotation {
+    SciNotation::from(value)
+}
+
+impl From<f64> for SciNotation {
+    fn from(value: f64) -> Self {
+        SciNotation::from_decimal(Decimal::from_f64(value))
+    }
+}
+
+/// Uses the shortest mantissa which identifies the [half::f16].
+///
+/// This requires the `half` feature.
+///
+/// ```
+/// # use indexing_fmt::sci::*;
+/// let value = half::f16::from_f32(0.1);
+/// assert_eq!(format!("{}", SciNotation::from(value)), "1×10⁻¹");
+/// ```
+#[cfg(feature = "half")]
+impl From<half::f16> for SciNotation {
+    fn from(value: half::f16) -> Self {
+        let round_trips = |v| half::f16::from_f64(v).to_bits() == value.to_bits();
+        SciNotation::from_decimal(Decimal::from_f64_shortest(value.to_f64(), round_trips))
+    }
+}
+
+/// Uses the shortest mantissa which identifies the [half::bf16].
+///
+/// This requires the `half` feature.
+#[cfg(feature = "half")]
+impl From<half::bf16> for SciNotation {
+    fn from(value: half::bf16) -> Self {
+        let round_trips = |v| half::bf16::from_f64(v).to_bits() == value.to_bits();
+        SciNotation::from_decimal(Decimal::from_f64_shortest(value.to_f64(), round_trips))
+    }
+}
+
+impl SciNotation {
+    /// The number `mantissa×10^exponent`, which is normalized before it is displayed.
+    ///
+    /// The exponent of the normalized number saturates at the bounds of [i32].
+    ///
+    /// ```
+    /// # use indexing_fmt::sci::*;
+    /// assert_eq!(format!("{}", SciNotation::new(4.2, -4)), "4.2×10⁻⁴");
+    /// assert_eq!(format!("{}", SciNotation::new(42.0, -5)), "4.2×10⁻⁴");
+    /// ```
+    pub fn new(mantissa: f64, exponent: i32) -> Self {
+        SciNotation::from_decimal(Decimal::from_f64(mantissa).shifted(exponent))
+    }
+
+    pub(crate) fn from_decimal(value: Decimal) -> Self {
+        SciNotation {
+            value,
+            options: Options::DEFAULT,
+        }
+    }
+
+    /// Rounds the mantissa to the given number of significant figures.
+    ///
+    /// ```
+    /// # use indexing_fmt::sci::*;
+    /// assert_eq!(format!("{}", sci(299_792_458.0).significant_figures(3)), "3.00×10⁸");
+    /// ```
+    pub fn significant_figures(mut self, significant_figures: usize) -> Self {
+        self.options.significant_figures = Some(significant_figures);
+        self
+    }
+
+    /// Selects how the mantissa is rounded to the significant figures, see [Rounding].
+    pub fn rounding(mut self, rounding: Rounding) -> Self {
+        self.options.rounding = rounding;
+        self
+    }
+
+    /// Sets the character which separates the integer and fractional part of the mantissa.
+    ///
+    /// ```
+    /// # use indexing_fmt::sci::*;
+    /// assert_eq!(format!("{}", sci(0.0025).decimal_separator(',')), "2,5×10⁻³");
+    /// ```
+    pub fn decimal_separator(mut self, decimal_separator: char) -> Self {
+        self.options.decimal_separator = decimal_separator;
+        self
+    }
+
+    /// Sets the sign between the mantissa and the power of ten, which defaults to `×`.
+    ///
+    /// ```
+    /// # use indexing_fmt::sci::*;
+    /// assert_eq!(format!("{}", sci(0.0025).multiplication_sign('·')), "2.5·10⁻³");
+    /// ```
+    pub fn multiplication_sign(mut self, multiplication_sign: char) -> Self {
+        self.options.multiplication_sign = multiplication_sign;
+        self
+    }
+
+    /// Selects how NaN and infinite values are written, see [NonFinite].
+    pub fn non_finite(mut self, non_finite: NonFinite) -> Self {
+        self.options.non_finite = non_finite;
+        self
+    }
+
+    /// Restricts the exponent to multiples of three, such that the mantissa lies between 1 and
+    /// 1000.
+    ///
+    /// ```
+    /// # use indexing_fmt::sci::*;
+    /// assert_eq!(format!("{}", sci(47_000.0).engineering()), "47×10³");
+    /// assert_eq!(format!("{}", sci(0.00042).engineering()), "420×10⁻⁶");
+    /// ```
+    pub fn engineering(mut self) -> Self {
+        self.options.engineering = true;
+        self
+    }
+}
+
+impl core::fmt::Display for SciNotation {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let value = self.options.apply(self.value);
+        f.write_str(self.options.sign(&value))?;
+        if let Some(text) = self.options.non_finite_text(&value)? {
+            return f.write_str(text);
+        }
+        let exponent = self.options.write_mantissa(f, &value)?;
+        f.write_char(self.options.multiplication_sign)?;
+        write!(f, "10{}", Power(exponent))
+    }
+}
+
+/// Displays the exponent of `10ⁿ`, or `10^n` in [plain mode](crate::plain_mode), where `103` would
+/// be misread.
+struct Power(i32);
+
+impl core::fmt::Display for Power {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match crate::plain_mode() {
+            true => write!(f, "^{}", self.0),
+            false => write!(f, "{}", self.0.to_superscript()),
+        }
+    }
+}
+
+/// The SI prefixes from `10⁻³⁰` to `10³⁰`.
+const SI_PREFIXES: [(i32, &str); 21] = [
+    (-30, "q"),
+    (-27, "r"),
+    (-24, "y"),
+    (-21, "z"),
+    (-18, "a"),
+    (-15, "f"),
+    (-12, "p"),
+    (-9, "n"),
+    (-6, "\u{00b5}"),
+    (-3, "m"),
+    (0, ""),
+    (3, "k"),
+    (6, "M"),
+    (9, "G"),
+    (12, "T"),
+    (15, "P"),
+    (18, "E"),
+    (21, "Z"),
+    (24, "Y"),
+    (27, "R"),
+    (30, "Q"),
+];
+
+/// Displays a quantity with the SI prefix closest to its magnitude, e.g. `3.2 µs`.
+///
+/// Only the prefixes from nano to giga are used by default. Values outside of this range are
+/// written in scientific notation instead.
+///
+/// ```
+/// # use indexing_fmt::sci::*;
+/// assert_eq!(format!("{}", si(3.2e-6, "s")), "3.2 µs");
+/// assert_eq!(format!("{}", si(1.1e-10, "s")), "1.1×10⁻¹⁰ s");
+/// assert_eq!(format!("{}", si(1.1e-10, "s").prefix_range(-12, 12)), "110 ps");
+/// assert_eq!(format!("{}", si(-4500.0, "W").significant_figures(3)), "-4.50 kW");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SiPrefixed<'a> {
+    value: Decimal,
+    unit: &'a str,
+    min_exponent: i32,
+    max_exponent: i32,
+    options: Options,
+}
+
+/// See [SiPrefixed].
+pub fn si(value: f64, unit: &str) -> SiPrefixed<'_> {
+    SiPrefixed {
+        value: Decimal::from_f64(value),
+        unit,
+        min_exponent: -9,
+        max_exponent: 9,
+        options: Options::DEFAULT,
+    }
+}
+
+impl SiPrefixed<'_> {
+    /// Restricts the prefixes to the powers of ten from `10ᵐⁱⁿ` to `10ᵐᵃˣ`.
+    ///
+    /// All prefixes from quecto `10⁻³⁰` to quetta `10³⁰` are available.
+    pub fn prefix_range(self, min_exponent: i32, max_exponent: i32) -> Self {
+        SiPrefixed {
+            min_exponent,
+            max_exponent,
+            ..self
+        }
+    }
+
+    /// See [SciNotation::significant_figures].
+    pub fn significant_figures(mut self, significant_figures: usize) -> Self {
+        self.options.significant_figures = Some(significant_figures);
+        self
+    }
+
+    /// See [SciNotation::rounding].
+    pub fn rounding(mut self, rounding: Rounding) -> Self {
+        self.options.rounding = rounding;
+        self
+    }
+
+    /// See [SciNotation::decimal_separator].
+    pub fn decimal_separator(mut self, decimal_separator: char) -> Self {
+        self.options.decimal_separator = decimal_separator;
+        self
+    }
+
+    /// See [SciNotation::multiplication_sign].
+    pub fn multiplication_sign(mut self, multiplication_sign: char) -> Self {
+        self.options.multiplication_sign = multiplication_sign;
+        self
+    }
+
+    /// See [SciNotation::non_finite].
+    pub fn non_finite(mut self, non_finite: NonFinite) -> Self {
+        self.options.non_finite = non_finite;
+        self
+    }
+}
+
+impl core::fmt::Display for SiPrefixed<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let value = self.options.apply(self.value);
+        let group = match value.is_zero() {
+            true => 0,
+            false => value.exponent.div_euclid(3).saturating_mul(3),
+        };
+        let prefix = SI_PREFIXES
+            .iter()
+            .find(|(exponent, _)| *exponent == group)
+            .filter(|(exponent, _)| (self.min_exponent..=self.max_exponent).contains(exponent));
+        match (prefix, value.kind) {
+            (Some((exponent, prefix)), Kind::Finite) => {
+                f.write_str(self.options.sign(&value))?;
+                value
+                    .shifted(-exponent)
+                    .write_fixed(f, self.options.decimal_separator)?;
+                write!(f, " {}{}", prefix, self.unit)
+            }
+            _ => {
+                let value = SciNotation {
+                    value,
+                    options: self.options,
+                };
+                write!(f, "{} {}", value, self.unit)
+            }
+        }
+    }
+}
+
+/// Displays a [Duration] in seconds using scientific notation or SI prefixes.
+///
+/// ```
+/// # use indexing_fmt::sci::*;
+/// use core::time::Duration;
+///
+/// let duration = Duration::from_micros(1500);
+/// assert_eq!(format!("{}", SciDuration::new(duration)), "1.5×10⁻³ s");
+/// assert_eq!(format!("{}", SciDuration::new(duration).si_prefix()), "1.5 ms");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SciDuration {
+    duration: Duration,
+    si_prefix: bool,
+    options: Options,
+}
+
+impl SciDuration {
+    pub fn new(duration: Duration) -> Self {
+        SciDuration {
+            duration,
+            si_prefix: false,
+            options: Options::DEFAULT,
+        }
+    }
+
+    /// Uses the prefixes `m`, `µ` and `n` instead of powers of ten.
+    ///
+    /// Durations above one second are always displayed in seconds.
+    pub fn si_prefix(self) -> Self {
+        SciDuration {
+            si_prefix: true,
+            ..self
+        }
+    }
+
+    /// See [SciNotation::significant_figures].
+    pub fn significant_figures(mut self, significant_figures: usize) -> Self {
+        self.options.significant_figures = Some(significant_figures);
+        self
+    }
+
+    /// See [SciNotation::rounding].
+    pub fn rounding(mut self, rounding: Rounding) -> Self {
+        self.options.rounding = rounding;
+        self
+    }
+
+    /// See [SciNotation::decimal_separator].
+    pub fn decimal_separator(mut self, decimal_separator: char) -> Self {
+        self.options.decimal_separator = decimal_separator;
+        self
+    }
+
+    /// See [SciNotation::multiplication_sign].
+    pub fn multiplication_sign(mut self, multiplication_sign: char) -> Self {
+        self.options.multiplication_sign = multiplication_sign;
+        self
+    }
+}
+
+impl core::fmt::Display for SciDuration {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let value = Decimal::from_u128(self.duration.as_nanos(), -9);
+        if !self.si_prefix {
+            let value = SciNotation {
+                value,
+                options: self.options,
+            };
+            return write!(f, "{} s", value);
+        }
+
+        let value = self.options.apply(value);
+        let (shift, prefix) = match value.exponent {
+            _ if value.is_zero() => (0, ""),
+            e if e >= 0 => (0, ""),
+            -3..=-1 => (3, "m"),
+            -6..=-4 => (6, "\u{00b5}"),
+            _ => (9, "n"),
+        };
+        value
+            .shifted(shift)
+            .write_fixed(f, self.options.decimal_separator)?;
+        write!(f, " {}s", prefix)
+    }
+}
+
+/// A power of ten shared by a collection of values.
+///
+/// This is the offset label known from the axes of plotting libraries: the values are displayed
+/// as scaled mantissas while the common factor is shown once.
+///
+/// ```
+/// # use indexing_fmt::sci::*;
+/// let values = [1200.0, 3400.0, 560.0];
+/// let common = CommonExponent::from_values(&values);
+/// assert_eq!(common.exponent(), 3);
+/// assert_eq!(format!("{}", common), "×10³");
+///
+/// let mantissas: Vec<_> = common.scaled(&values).collect();
+/// assert_eq!(mantissas, [1.2, 3.4, 0.56]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommonExponent {
+    exponent: i32,
+}
+
+impl CommonExponent {
+    /// Picks the exponent of the value with the largest magnitude.
+    ///
+    /// Non-finite values are ignored. If no non-zero finite value is present, the exponent is
+    /// zero.
+    pub fn from_values(values: &[f64]) -> Self {
+        let max = values
+            .iter()
+            .map(|v| v.abs())
+            .filter(|v| v.is_finite())
+            .fold(0.0, f64::max);
+        CommonExponent {
+            exponent: Decimal::from_f64(max).exponent,
+        }
+    }
+
+    pub fn new(exponent: i32) -> Self {
+        CommonExponent { exponent }
+    }
+
+    pub fn exponent(&self) -> i32 {
+        self.exponent
+    }
+
+    /// Divides the value by the common power of ten.
+    ///
+    /// The division is carried out in decimal so that no additional rounding errors occur.
+    pub fn scale(&self, value: f64) -> f64 {
+        Decimal::from_f64(value)
+            .shifted(self.exponent.saturating_neg())
+            .to_f64()
+    }
+
+    /// Iterates over the scaled mantissas of all values.
+    pub fn scaled<'a>(&self, values: &'a [f64]) -> impl Iterator<Item = f64> + 'a {
+        let common = *self;
+        values.iter().map(move |v| common.scale(*v))
+    }
+}
+
+/// Displays the label `×10ⁿ` or nothing if the exponent is zero.
+impl core::fmt::Display for CommonExponent {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.exponent == 0 {
+            return Ok(());
+        }
+        write!(f, "×10{}", Power(self.exponent))
+    }
+}
+
+/// Counts the characters written to it.
+#[derive(Default)]
+struct Width(usize);
+
+impl Write for Width {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+fn pad(f: &mut impl Write, width: usize) -> core::fmt::Result {
+    (0..width).try_for_each(|_| f.write_char(' '))
+}
+
+/// Widths of the sign, the mantissa and the exponent in a column of [SciNotation] values.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+struct Columns {
+    sign: usize,
+    mantissa: usize,
+    exponent: usize,
+}
+
+impl Columns {
+    fn max(self, other: Columns) -> Columns {
+        Columns {
+            sign: self.sign.max(other.sign),
+            mantissa: self.mantissa.max(other.mantissa),
+            exponent: self.exponent.max(other.exponent),
+        }
+    }
+}
+
+impl SciNotation {
+    fn columns(&self) -> Columns {
+        let value = self.options.apply(self.value);
+        let mut mantissa = Width::default();
+        let mut exponent = Width::default();
+        if value.kind == Kind::Finite {
+            let e = self
+                .options
+                .write_mantissa(&mut mantissa, &value)
+                .unwrap_or(0);
+            let _ = write!(exponent, "{}", Power(e));
+        }
+        Columns {
+            sign: self.options.sign(&value).len(),
+            mantissa: mantissa.0,
+            exponent: exponent.0,
+        }
+    }
+}
+
+/// Formats a column of values in scientific notation such that the `×10ⁿ` parts line up.
+///
+/// Mantissas and exponents are padded with spaces to the widths of the longest ones in the
+/// column. When any value is negative, positive values are preceded by a space.
+///
+/// ```
+/// # use indexing_fmt::sci::*;
+/// let values = [1500.0, -2.25e-12, 7.0];
+/// let lines: Vec<_> = SciColumn::new(&values).iter().map(|v| v.to_string()).collect();
+/// assert_eq!(lines, [" 1.5 ×10³  ", "-2.25×10⁻¹²", " 7   ×10⁰  "]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SciColumn<'a> {
+    values: &'a [f64],
+    options: Options,
+}
+
+impl<'a> SciColumn<'a> {
+    pub fn new(values: &'a [f64]) -> Self {
+        SciColumn {
+            values,
+            options: Options::DEFAULT,
+        }
+    }
+
+    /// See [SciNotation::significant_figures].
+    pub fn significant_figures(mut self, significant_figures: usize) -> Self {
+        self.options.significant_figures = Some(significant_figures);
+        self
+    }
+
+    /// See [SciNotation::rounding].
+    pub fn rounding(mut self, rounding: Rounding) -> Self {
+        self.options.rounding = rounding;
+        self
+    }
+
+    /// See [SciNotation::decimal_separator].
+    pub fn decimal_separator(mut self, decimal_separator: char) -> Self {
+        self.options.decimal_separator = decimal_separator;
+        self
+    }
+
+    /// See [SciNotation::multiplication_sign].
+    pub fn multiplication_sign(mut self, multiplication_sign: char) -> Self {
+        self.options.multiplication_sign = multiplication_sign;
+        self
+    }
+
+    /// See [SciNotation::non_finite].
+    pub fn non_finite(mut self, non_finite: NonFinite) -> Self {
+        self.options.non_finite = non_finite;
+        self
+    }
+
+    /// See [SciNotation::engineering].
+    pub fn engineering(mut self) -> Self {
+        self.options.engineering = true;
+        self
+    }
+
+    /// Iterates over the aligned values.
+    pub fn iter(&self) -> impl Iterator<Item = AlignedSci> + 'a {
+        let options = self.options;
+        let notation = move |value: &f64| SciNotation {
+            value: Decimal::from_f64(*value),
+            options,
+        };
+        let columns = self
+            .values
+            .iter()
+            .map(|value| notation(value).columns())
+            .fold(Columns::default(), Columns::max);
+        self.values.iter().map(move |value| AlignedSci {
+            notation: notation(value),
+            columns,
+        })
+    }
+}
+
+/// A single value of a [SciColumn].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AlignedSci {
+    notation: SciNotation,
+    columns: Columns,
+}
+
+impl core::fmt::Display for AlignedSci {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let value = self.notation.options.apply(self.notation.value);
+        let options = self.notation.options;
+        let own = self.notation.columns();
+        f.write_str(options.sign(&value))?;
+        pad(f, self.columns.sign - own.sign)?;
+        if let Some(text) = options.non_finite_text(&value)? {
+            f.write_str(text)?;
+            let width = self.columns.mantissa + 3 + self.columns.exponent;
+            return pad(f, width.saturating_sub(text.chars().count()));
+        }
+        let exponent = options.write_mantissa(f, &value)?;
+        pad(f, self.columns.mantissa - own.mantissa)?;
+        f.write_char(options.multiplication_sign)?;
+        write!(f, "10{}", Power(exponent))?;
+        pad(f, self.columns.exponent - own.exponent)
+    }
+}
+
+/// Displays large numbers with suffixes such as `K`, `M` and `B` as commonly done in games.
+///
+/// Numbers beyond the largest suffix are displayed in scientific notation. By default, the
+/// mantissa is rounded to three significant figures. Numbers below a thousand are rounded as well,
+/// so that `999` with one significant figure becomes `1K`.
+///
+/// ```
+/// # use indexing_fmt::sci::*;
+/// assert_eq!(format!("{}", SuffixNotation::new(999)), "999");
+/// assert_eq!(format!("{}", SuffixNotation::new(12_345)), "12.3K");
+/// assert_eq!(format!("{}", SuffixNotation::new(7_650_000_000)), "7.65B");
+/// assert_eq!(format!("{}", SuffixNotation::new(123_456_789_000_000_000_000_000)), "1.23×10²³");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SuffixNotation<'a> {
+    value: Decimal,
+    significant_figures: usize,
+    rounding: Rounding,
+    decimal_separator: char,
+    suffixes: &'a [&'a str],
+}
+
+impl<'a> SuffixNotation<'a> {
+    /// Suffixes for the powers `10³, 10⁶, …, 10¹⁸`.
+    pub const DEFAULT_SUFFIXES: &'static [&'static str] = &["K", "M", "B", "T", "Qa", "Qi"];
+
+    pub fn new(value: u128) -> Self {
+        SuffixNotation {
+            value: Decimal::from_u128(value, 0),
+            significant_figures: 3,
+            rounding: Rounding::HalfEven,
+            decimal_separator: '.',
+            suffixes: Self::DEFAULT_SUFFIXES,
+        }
+    }
+
+    /// See [SciNotation::significant_figures].
+    pub fn significant_figures(self, significant_figures: usize) -> Self {
+        SuffixNotation {
+            significant_figures,
+            ..self
+        }
+    }
+
+    /// See [SciNotation::rounding].
+    ///
+    /// ```
+    /// # use indexing_fmt::sci::*;
+    /// let res = SuffixNotation::new(1_999).rounding(Rounding::TowardZero);
+    /// assert_eq!(format!("{}", res), "1.99K");
+    /// ```
+    pub fn rounding(self, rounding: Rounding) -> Self {
+        SuffixNotation { rounding, ..self }
+    }
+
+    /// See [SciNotation::decimal_separator].
+    pub fn decimal_separator(self, decimal_separator: char) -> Self {
+        SuffixNotation {
+            decimal_separator,
+            ..self
+        }
+    }
+
+    /// Replaces the suffixes, the `n`-th entry of which stands for `10³⁽ⁿ⁺¹⁾`.
+    pub fn suffixes(self, suffixes: &'a [&'a str]) -> Self {
+        SuffixNotation { suffixes, ..self }
+    }
+}
+
+impl core::fmt::Display for SuffixNotation<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let value = self.value.rounded(self.significant_figures, self.rounding);
+        if value.exponent < 3 {
+            // Drops the zeros which pad the rounded integer to the significant figures
+            let integer = value.rounded(value.exponent as usize + 1, Rounding::TowardZero);
+            return integer.write_fixed(f, self.decimal_separator);
+        }
+        let group = value.exponent as usize / 3;
+        match self.suffixes.get(group - 1) {
+            Some(suffix) => {
+                value
+                    .shifted(-3 * group as i32)
+                    .write_fixed(f, self.decimal_separator)?;
+                f.write_str(suffix)
+            }
+            None => write!(
+                f,
+                "{}",
+                SciNotation::from_decimal(value).decimal_separator(self.decimal_separator)
+            ),
+        }
+    }
+}
+
+/// Displays a product of units with superscript exponents like `kg·m²·s⁻²`.
+///
+/// Units with an exponent of zero are omitted and exponents of one are not written. The units are
+/// separated by a middle dot, which can be replaced by any [Spacing] such as [Spacing::Thin].
+///
+/// ```
+/// # use indexing_fmt::sci::*;
+/// use indexing_fmt::Spacing;
+///
+/// let joule = [("kg", 1), ("m", 2), ("s", -2)];
+/// assert_eq!(format!("{}", Units::new(&joule)), "kg·m²·s⁻²");
+/// assert_eq!(format!("{}", Units::new(&joule).separator(Spacing::Thin)), "kg\u{2009}m²\u{2009}s⁻²");
+/// assert_eq!(format!("{}", Units::new(&joule).solidus()), "kg·m²/s²");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Units<'a> {
+    units: &'a [(&'a str, i32)],
+    separator: Spacing,
+    solidus: bool,
+}
+
+impl<'a> Units<'a> {
+    /// The units as pairs of symbol and exponent, in the order they are written.
+    pub fn new(units: &'a [(&'a str, i32)]) -> Self {
+        Units {
+            units,
+            separator: Spacing::Custom('\u{b7}'),
+            solidus: false,
+        }
+    }
+
+    /// Replaces the middle dot between the units.
+    pub fn separator(self, separator: Spacing) -> Self {
+        Units { separator, ..self }
+    }
+
+    /// Writes the units with negative exponents after a solidus with positive exponents.
+    ///
+    /// Several units in the denominator are enclosed in parentheses and `1` is written if the
+    /// numerator is empty.
+    ///
+    /// ```
+    /// # use indexing_fmt::sci::*;
+    /// assert_eq!(format!("{}", Units::new(&[("m", 1), ("s", -2)]).solidus()), "m/s²");
+    /// assert_eq!(format!("{}", Units::new(&[("W", 1), ("m", -2), ("K", -1)]).solidus()), "W/(m²·K)");
+    /// assert_eq!(format!("{}", Units::new(&[("s", -1)]).solidus()), "1/s");
+    /// ```
+    pub fn solidus(self) -> Self {
+        Units {
+            solidus: true,
+            ..self
+        }
+    }
+
+    fn write_product(
+        &self,
+        f: &mut core::fmt::Formatter<'_>,
+        filter: impl Fn(i32) -> bool,
+        magnitude: bool,
+    ) -> core::fmt::Result {
+        let units = self.units.iter().filter(|(_, e)| *e != 0 && filter(*e));
+        for (i, (symbol, exponent)) in units.enumerate() {
+            if i > 0 {
+                write!(f, "{}", self.separator)?;
+            }
+            f.write_str(symbol)?;
+            match (exponent, magnitude) {
+                (1, _) | (-1, true) => {}
+                (e, true) => write!(f, "{}", e.unsigned_abs().to_superscript())?,
+                (e, false) => write!(f, "{}", e.to_superscript())?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl core::fmt::Display for Units<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if !self.solidus {
+            return self.write_product(f, |_| true, false);
+        }
+        let count = |filter: fn(i32) -> bool| self.units.iter().filter(|(_, e)| filter(*e)).count();
+        let denominator = count(|e| e < 0);
+        match count(|e| e > 0) {
+            0 if denominator > 0 => f.write_char('1')?,
+            _ => self.write_product(f, |e| e > 0, false)?,
+        }
+        match denominator {
+            0 => Ok(()),
+            1 => {
+                f.write_char('/')?;
+                self.write_product(f, |e| e < 0, true)
+            }
+            _ => {
+                f.write_str("/(")?;
+                self.write_product(f, |e| e < 0, true)?;
+                f.write_char(')')
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    extern crate std;
+
+    #[test]
+    fn sci_small_and_large() {
+        assert_eq!(std::format!("{}", sci(0.1)), "1×10⁻¹");
+        assert_eq!(std::format!("{}", sci(6.02214076e23)), "6.02214076×10²³");
+        assert_eq!(std::format!("{}", sci(0.0)), "0×10⁰");
+    }
+
+    #[test]
+    fn sci_rounding() {
+        assert_eq!(
+            std::format!("{}", sci(9.996).significant_figures(3)),
+            "1.00×10¹"
+        );
+        assert_eq!(
+            std::format!("{}", sci(0.125).significant_figures(2)),
+            "1.2×10⁻¹"
+        );
+        assert_eq!(
+            std::format!("{}", sci(0.135).significant_figures(2)),
+            "1.4×10⁻¹"
+        );
+    }
+
+    #[test]
+    fn engineering_notation() {
+        let res = sci(-4.7e4).engineering().significant_figures(3);
+        assert_eq!(std::format!("{}", res), "-47.0×10³");
+        assert_eq!(
+            std::format!("{}", sci(999.96).engineering().significant_figures(4)),
+            "1.000×10³"
+        );
+        assert_eq!(std::format!("{}", sci(0.0).engineering()), "0×10⁰");
+        let column = SciColumn::new(&[1.5e4, 2.0e-2]).engineering();
+        let lines: std::vec::Vec<_> = column.iter().map(|v| std::format!("{}", v)).collect();
+        assert_eq!(lines, ["15×10³ ", "20×10⁻³"]);
+    }
+
+    #[test]
+    fn multiplication_sign_everywhere() {
+        let res = std::format!("{}", si(1.1e-10, "s").multiplication_sign('·'));
+        assert_eq!(res, "1.1·10⁻¹⁰ s");
+        let duration = Duration::from_micros(2_250);
+        let res = SciDuration::new(duration).multiplication_sign('·');
+        assert_eq!(std::format!("{}", res), "2.25·10⁻³ s");
+        let column = SciColumn::new(&[1.5, 20.0]).multiplication_sign('·');
+        let lines: std::vec::Vec<_> = column.iter().map(|v| std::format!("{}", v)).collect();
+        assert_eq!(lines, ["1.5·10⁰", "2  ·10¹"]);
+    }
+
+    #[test]
+    fn decimal_separator_everywhere() {
+        let duration = Duration::from_micros(2_250);
+        let res = std::format!("{}", SciDuration::new(duration).decimal_separator(','));
+        assert_eq!(res, "2,25×10⁻³ s");
+        let res = SciDuration::new(duration)
+            .si_prefix()
+            .decimal_separator(',');
+        assert_eq!(std::format!("{}", res), "2,25 ms");
+        let res = SuffixNotation::new(12_345).decimal_separator(',');
+        assert_eq!(std::format!("{}", res), "12,3K");
+    }
+
+    #[test]
+    fn column_with_non_finite_values() {
+        let values = [-f64::INFINITY, 12.5, f64::NAN];
+        let column = SciColumn::new(&values).decimal_separator(',');
+        let lines: std::vec::Vec<_> = column.iter().map(|v| std::format!("{}", v)).collect();
+        assert_eq!(lines, ["-inf     ", " 1,25×10¹", " NaN     "]);
+        let column = SciColumn::new(&values[..2]).non_finite(NonFinite::SignedSymbol);
+        let lines: std::vec::Vec<_> = column.iter().map(|v| std::format!("{}", v)).collect();
+        assert_eq!(lines, ["-∞       ", " 1.25×10¹"]);
+        let column = SciColumn::new(&values).non_finite(NonFinite::Error);
+        let mut line = std::string::String::new();
+        let res = core::fmt::write(&mut line, format_args!("{}", column.iter().next().unwrap()));
+        assert!(res.is_err());
+    }
+
+    #[cfg(feature = "half")]
+    #[test]
+    fn half_precision() {
+        let value = half::f16::from_f32(65504.0);
+        assert_eq!(std::format!("{}", SciNotation::from(value)), "6.55×10⁴");
+        let value = half::f16::from_bits(1);
+        assert_eq!(std::format!("{}", SciNotation::from(value)), "6×10⁻⁸");
+        let value = half::bf16::from_f32(1.2345);
+        assert_eq!(std::format!("{}", SciNotation::from(value)), "1.234×10⁰");
+        let value = -half::bf16::INFINITY;
+        assert_eq!(std::format!("{}", SciNotation::from(value)), "-inf");
+    }
+
+    #[test]
+    fn rounding_modes_change_prefix() {
+        let duration = SciDuration::new(Duration::from_nanos(999_500)).si_prefix();
+        let res = duration
+            .significant_figures(3)
+            .rounding(Rounding::TowardZero);
+        assert_eq!(std::format!("{}", res), "999 \u{00b5}s");
+        let res = duration.significant_figures(3).rounding(Rounding::HalfUp);
+        assert_eq!(std::format!("{}", res), "1.00 ms");
+        let duration = SciDuration::new(Duration::from_nanos(999_999)).si_prefix();
+        assert_eq!(
+            std::format!("{}", duration.significant_figures(2)),
+            "1.0 ms"
+        );
+    }
+
+    #[test]
+    fn si_prefix_rounding_and_zero() {
+        assert_eq!(
+            std::format!("{}", si(999_960.0, "Hz").significant_figures(3)),
+            "1.00 MHz"
+        );
+        assert_eq!(std::format!("{}", si(0.0, "m")), "0 m");
+        assert_eq!(std::format!("{}", si(2.5e40, "J")), "2.5×10⁴⁰ J");
+        assert_eq!(std::format!("{}", si(f64::NAN, "J")), "NaN J");
+    }
+
+    #[test]
+    fn common_exponent_small_values() {
+        let values = [-0.0025, 0.0001, f64::NAN];
+        let common = CommonExponent::from_values(&values);
+        assert_eq!(std::format!("{}", common), "×10⁻³");
+        assert_eq!(common.scale(values[0]), -2.5);
+        assert_eq!(common.scale(values[1]), 0.1);
+        assert!(common.scale(values[2]).is_nan());
+    }
+
+    #[test]
+    fn common_exponent_zero() {
+        let common = CommonExponent::from_values(&[0.0, 3.0]);
+        assert_eq!(common.exponent(), 0);
+        assert_eq!(std::format!("{}", common), "");
+        assert_eq!(CommonExponent::from_values(&[]).exponent(), 0);
+    }
+
+    #[test]
+    fn suffix_rounding_to_next_suffix() {
+        assert_eq!(std::format!("{}", SuffixNotation::new(999_999)), "1.00M");
+        assert_eq!(
+            std::format!("{}", SuffixNotation::new(999_999_999_999_999_999_999)),
+            "1.00×10²¹"
+        );
+        let res = SuffixNotation::new(1_500).significant_figures(1);
+        assert_eq!(std::format!("{}", res), "2K");
+        let res = SuffixNotation::new(999).significant_figures(1);
+        assert_eq!(std::format!("{}", res), "1K");
+        assert_eq!(std::format!("{}", SuffixNotation::new(999)), "999");
+    }
+
+    #[test]
+    fn suffix_small_integers() {
+        assert_eq!(std::format!("{}", SuffixNotation::new(7)), "7");
+        let res = SuffixNotation::new(123).significant_figures(1);
+        assert_eq!(std::format!("{}", res), "100");
+        let res = SuffixNotation::new(994).significant_figures(2);
+        assert_eq!(std::format!("{}", res), "990");
+    }
+
+    #[test]
+    fn suffix_custom_table() {
+        let res = SuffixNotation::new(2_500_000).suffixes(&["k"]);
+        assert_eq!(std::format!("{}", res), "2.50×10⁶");
+        let res = SuffixNotation::new(0).suffixes(&[]);
+        assert_eq!(std::format!("{}", res), "0");
+    }
+
+    #[test]
+    fn duration_si_prefix() {
+        let format = |d| std::format!("{}", SciDuration::new(d).si_prefix());
+        assert_eq!(format(Duration::from_nanos(42)), "42 ns");
+        assert_eq!(format(Duration::from_nanos(3_200)), "3.2 µs");
+        assert_eq!(format(Duration::from_millis(999)), "999 ms");
+        assert_eq!(format(Duration::from_millis(12_500)), "12.5 s");
+        assert_eq!(format(Duration::ZERO), "0 s");
+    }
+
+    #[test]
+    fn extreme_exponents() {
+        let res = std::format!("{}", SciNotation::new(42.0, i32::MAX));
+        assert_eq!(res, std::format!("4.2×10{}", i32::MAX.to_superscript()));
+        let res = std::format!("{}", SciNotation::new(0.042, i32::MIN).engineering());
+        assert_eq!(res, std::format!("4.2×10{}", i32::MIN.to_superscript()));
+        let res = SciNotation::new(9.99, i32::MAX).significant_figures(2);
+        assert_eq!(
+            std::format!("{}", res),
+            std::format!("1.0×10{}", i32::MAX.to_superscript())
+        );
+        assert_eq!(CommonExponent::new(i32::MIN).scale(0.0), 0.0);
+    }
+
+    #[test]
+    fn unit_products() {
+        let units = [("N", 1), ("mol", 0), ("m", 1)];
+        assert_eq!(std::format!("{}", Units::new(&units)), "N·m");
+        assert_eq!(std::format!("{}", Units::new(&units).solidus()), "N·m");
+        assert_eq!(std::format!("{}", Units::new(&[])), "");
+        let units = [("s", -1), ("kg", -3), ("A", 2)];
+        let res = Units::new(&units).separator(Spacing::None).solidus();
+        assert_eq!(std::format!("{}", res), "A²/(skg³)");
+        assert_eq!(std::format!("{}", Units::new(&units)), "s⁻¹·kg⁻³·A²");
+        let res = Units::new(&[("m", i32::MIN)]).solidus();
+        assert_eq!(std::format!("{}", res), "1/m²¹⁴⁷⁴⁸³⁶⁴⁸");
+    }
+}
@@ -0,0 +1,101 @@
+//! A base with both a subscript and a superscript attached, such as `x₁²`.
+//!
+//! ```
+//! use indexing_fmt::scripted::scripted;
+//!
+//! assert_eq!(format!("{}", scripted("x").subscript(1).superscript(2)), "x₁²");
+//! assert_eq!(format!("{}", scripted('T').subscript("ij").superscript(-1)), "Tᵢⱼ⁻¹");
+//! ```
+
+use core::fmt::{Display, Write};
+
+use crate::chars::{Fallback, Mapped, subscript_char, superscript_char};
+
+/// Displays the base followed by the subscript and then the superscript.
+///
+/// Scripts can be any [Display] value whose output is mapped character by character. Characters
+/// without script form are written according to the [Fallback], which keeps them by default.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Scripted<D, B = &'static str, P = &'static str> {
+    base: D,
+    subscript: Option<B>,
+    superscript: Option<P>,
+    fallback: Fallback,
+}
+
+/// Starts a [Scripted] value without scripts.
+pub fn scripted<D: Display>(base: D) -> Scripted<D> {
+    Scripted {
+        base,
+        subscript: None,
+        superscript: None,
+        fallback: Fallback::Keep,
+    }
+}
+
+impl<D, B, P> Scripted<D, B, P> {
+    /// Attaches the subscript, replacing a previous one.
+    pub fn subscript<S: Display>(self, subscript: S) -> Scripted<D, S, P> {
+        Scripted {
+            base: self.base,
+            subscript: Some(subscript),
+            superscript: self.superscript,
+            fallback: self.fallback,
+        }
+    }
+
+    /// Attaches the superscript, replacing a previous one.
+    pub fn superscript<S: Display>(self, superscript: S) -> Scripted<D, B, S> {
+        Scripted {
+            base: self.base,
+            subscript: self.subscript,
+            superscript: Some(superscript),
+            fallback: self.fallback,
+        }
+    }
+
+    /// Determines how characters without script form are written.
+    pub fn fallback(self, fallback: Fallback) -> Self {
+        Scripted { fallback, ..self }
+    }
+}
+
+impl<D: Display, B: Display, P: Display> Display for Scripted<D, B, P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.base)?;
+        if let Some(subscript) = &self.subscript {
+            let mut mapped = Mapped {
+                inner: f,
+                map: subscript_char,
+                fallback: self.fallback,
+            };
+            write!(mapped, "{}", subscript)?;
+        }
+        if let Some(superscript) = &self.superscript {
+            let mut mapped = Mapped {
+                inner: f,
+                map: superscript_char,
+                fallback: self.fallback,
+            };
+            write!(mapped, "{}", superscript)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    extern crate std;
+
+    #[test]
+    fn single_and_missing_scripts() {
+        assert_eq!(std::format!("{}", scripted("e").superscript("iπ")), "eⁱπ");
+        assert_eq!(std::format!("{}", scripted(1.5).subscript(2u8)), "1.5₂");
+        let res = scripted("a")
+            .subscript("q")
+            .fallback(Fallback::Replace('?'));
+        assert_eq!(std::format!("{}", res), "a?");
+        assert_eq!(std::format!("{}", scripted("y")), "y");
+    }
+}
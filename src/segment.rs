@@ -0,0 +1,123 @@
+//! Runs of baseline, superscript and subscript text for renderers with real typographic scripts.
+//!
+//! Instead of Unicode script characters, types implementing [WriteSegments] emit [Segment]s
+//! containing the baseline form of their text. GUI and PDF renderers can then shift and scale the
+//! script runs with the fonts at hand. Consecutive segments may have the same kind.
+//!
+//! ```
+//! use indexing_fmt::chem::formula;
+//! use indexing_fmt::segment::*;
+//!
+//! let mut segments = Vec::new();
+//! formula("H2SO4")
+//!     .write_segments(&mut |segment| {
+//!         segments.push(format!("{:?}", segment));
+//!         Ok(())
+//!     })
+//!     .unwrap();
+//! assert_eq!(
+//!     segments,
+//!     [
+//!         "Text(\"H\")",
+//!         "Subscript(\"2\")",
+//!         "Text(\"SO\")",
+//!         "Subscript(\"4\")"
+//!     ]
+//! );
+//! ```
+
+use core::fmt::Write;
+
+use crate::decimal::Buf;
+use crate::{Subscript, Superscript};
+
+/// A run of text and its vertical placement.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Segment<'a> {
+    Text(&'a str),
+    Superscript(&'a str),
+    Subscript(&'a str),
+}
+
+/// Passes the segments of a value to a callback in reading order.
+pub trait WriteSegments {
+    fn write_segments(
+        &self,
+        sink: &mut dyn FnMut(Segment<'_>) -> core::fmt::Result,
+    ) -> core::fmt::Result;
+}
+
+macro_rules! impl_write_segments(
+    ($($ty:ty),*) => {
+        $(
+            impl WriteSegments for Superscript<$ty> {
+                fn write_segments(
+                    &self,
+                    sink: &mut dyn FnMut(Segment<'_>) -> core::fmt::Result,
+                ) -> core::fmt::Result {
+                    let mut buf = Buf::new();
+                    write!(buf, "{}", self.0)?;
+                    sink(Segment::Superscript(buf.as_str()))
+                }
+            }
+
+            impl WriteSegments for Subscript<$ty> {
+                fn write_segments(
+                    &self,
+                    sink: &mut dyn FnMut(Segment<'_>) -> core::fmt::Result,
+                ) -> core::fmt::Result {
+                    let mut buf = Buf::new();
+                    write!(buf, "{}", self.0)?;
+                    sink(Segment::Subscript(buf.as_str()))
+                }
+            }
+        )*
+    };
+);
+
+impl_write_segments!(
+    u128, i128, usize, isize, u64, i64, u32, i32, u16, i16, u8, i8
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::chem::*;
+    extern crate std;
+
+    fn segments(value: &impl WriteSegments) -> std::vec::Vec<std::string::String> {
+        let mut segments = std::vec::Vec::new();
+        value
+            .write_segments(&mut |segment| {
+                segments.push(std::format!("{:?}", segment));
+                Ok(())
+            })
+            .unwrap();
+        segments
+    }
+
+    #[test]
+    fn integers_and_complexes() {
+        assert_eq!(segments(&Superscript(-12)), ["Superscript(\"-12\")"]);
+        assert_eq!(
+            segments(&Subscript(i128::MIN)),
+            ["Subscript(\"-170141183460469231731687303715884105728\")"]
+        );
+        let ligands = [Ligand::new("NH3", 4)];
+        let complex = Complex::new("Cu", &ligands, 2);
+        assert_eq!(
+            segments(&complex),
+            [
+                "Text(\"[\")",
+                "Text(\"Cu\")",
+                "Text(\"(\")",
+                "Text(\"NH\")",
+                "Subscript(\"3\")",
+                "Text(\")\")",
+                "Subscript(\"4\")",
+                "Text(\"]\")",
+                "Superscript(\"2+\")"
+            ]
+        );
+    }
+}
@@ -0,0 +1,54 @@
+//! Conversion into small-string types of other crates.
+
+use core::fmt::Display;
+
+use crate::{Subscript, Superscript};
+
+macro_rules! impl_small_strings(
+    ($ty:ident) => {
+        impl<T> $ty<T>
+        where
+            Self: Display,
+        {
+            /// Formats the script into a [compact_str::CompactString].
+            ///
+            /// This method requires the `compact_str` feature.
+            #[cfg(feature = "compact_str")]
+            pub fn to_compact_string(&self) -> compact_str::CompactString {
+                compact_str::ToCompactString::to_compact_string(self)
+            }
+
+            /// Formats the script into a [smol_str::SmolStr].
+            ///
+            /// This method requires the `smol_str` feature.
+            #[cfg(feature = "smol_str")]
+            pub fn to_smolstr(&self) -> smol_str::SmolStr {
+                smol_str::ToSmolStr::to_smolstr(self)
+            }
+        }
+    };
+);
+
+impl_small_strings!(Superscript);
+impl_small_strings!(Subscript);
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[cfg(feature = "compact_str")]
+    #[test]
+    fn compact_string() {
+        let res = (-12).to_superscript().to_compact_string();
+        assert_eq!(res, "⁻¹²");
+        assert!(!res.is_heap_allocated());
+    }
+
+    #[cfg(feature = "smol_str")]
+    #[test]
+    fn smol_str() {
+        let res = 840.to_subscript().to_smolstr();
+        assert_eq!(res, "₈₄₀");
+        assert!(!res.is_heap_allocated());
+    }
+}
@@ -0,0 +1,331 @@
+//! Alternative presentations of the decimal digits outside of scripts.
+//!
+//! ```
+//! use indexing_fmt::styled::*;
+//!
+//! assert_eq!(format!("x{}", 12.to_styled_digits(Style::DoubleStruck)), "x𝟙𝟚");
+//! assert_eq!(format!("{}", (-0.5).to_styled_digits(Style::Bold)), "-𝟎.𝟓");
+//! assert_eq!(format!("{} {} {}", 3.to_circled(), 4.to_parenthesized(), 5.to_full_stop()), "③ ⑷ ⒌");
+//! ```
+
+use core::fmt::{Display, Write};
+
+use crate::Fallback;
+
+/// The digit styles of the Unicode block Mathematical Alphanumeric Symbols and the fullwidth digits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Style {
+    /// `𝟎𝟏𝟐𝟑𝟒𝟓𝟔𝟕𝟖𝟗`
+    Bold,
+    /// `𝟘𝟙𝟚𝟛𝟜𝟝𝟞𝟟𝟠𝟡`
+    DoubleStruck,
+    /// `𝟢𝟣𝟤𝟥𝟦𝟧𝟨𝟩𝟪𝟫`
+    SansSerif,
+    /// `𝟬𝟭𝟮𝟯𝟰𝟱𝟲𝟳𝟴𝟵`
+    SansSerifBold,
+    /// `𝟶𝟷𝟸𝟹𝟺𝟻𝟼𝟽𝟾𝟿`
+    Monospace,
+    /// `０１２３４５６７８９`, which are as wide as CJK characters
+    Fullwidth,
+}
+
+impl Style {
+    /// The styled form of a decimal digit.
+    ///
+    /// ```
+    /// # use indexing_fmt::styled::*;
+    /// assert_eq!(Style::Monospace.digit(7), '𝟽');
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If the digit is larger than 9.
+    pub const fn digit(self, d: u8) -> char {
+        assert!(d <= 9, "not a decimal digit");
+        let zero = match self {
+            Style::Bold => 0x1d7ce,
+            Style::DoubleStruck => 0x1d7d8,
+            Style::SansSerif => 0x1d7e2,
+            Style::SansSerifBold => 0x1d7ec,
+            Style::Monospace => 0x1d7f6,
+            Style::Fullwidth => 0xff10,
+        };
+        match char::from_u32(zero + d as u32) {
+            Some(c) => c,
+            None => unreachable!(),
+        }
+    }
+}
+
+/// Displays the inner value with its decimal digits in a [Style].
+///
+/// All other characters, including signs and the decimal point, are kept.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct StyledDigits<D> {
+    value: D,
+    style: Style,
+}
+
+impl<D> StyledDigits<D> {
+    pub fn new(value: D, style: Style) -> Self {
+        StyledDigits { value, style }
+    }
+}
+
+struct StyleWriter<'a, W> {
+    inner: &'a mut W,
+    style: Style,
+}
+
+impl<W: Write> Write for StyleWriter<'_, W> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        s.chars().try_for_each(|c| self.write_char(c))
+    }
+
+    fn write_char(&mut self, c: char) -> core::fmt::Result {
+        match c {
+            '0'..='9' if !crate::plain_mode() => {
+                self.inner.write_char(self.style.digit(c as u8 - b'0'))
+            }
+            c => self.inner.write_char(c),
+        }
+    }
+}
+
+impl<D: Display> Display for StyledDigits<D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut styled = StyleWriter {
+            inner: f,
+            style: self.style,
+        };
+        write!(styled, "{}", self.value)
+    }
+}
+
+/// Responsible for converting to the alternative digit presentations.
+///
+/// This trait is implemented for all types which implement [Display].
+pub trait FormatStyled: Display + Sized {
+    /// Writes the decimal digits in the given [Style].
+    fn to_styled_digits(&self, style: Style) -> StyledDigits<&Self> {
+        StyledDigits::new(self, style)
+    }
+
+    /// Writes the decimal digits as fullwidth digits.
+    ///
+    /// ```
+    /// # use indexing_fmt::styled::*;
+    /// assert_eq!(format!("{}年", 2024.to_fullwidth()), "２０２４年");
+    /// ```
+    fn to_fullwidth(&self) -> StyledDigits<&Self> {
+        self.to_styled_digits(Style::Fullwidth)
+    }
+}
+
+impl<T: Display> FormatStyled for T {}
+
+/// The enclosed forms of the Unicode block Enclosed Alphanumerics and its supplements.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Enclosure {
+    /// `⓪①②…㊿` for 0 to 50
+    Circled,
+    /// `⑴⑵⑶…⒇` for 1 to 20
+    Parenthesized,
+    /// `🄀⒈⒉…⒛` for 0 to 20
+    FullStop,
+}
+
+impl Enclosure {
+    /// The enclosed character of the number if one exists.
+    ///
+    /// ```
+    /// # use indexing_fmt::styled::*;
+    /// assert_eq!(Enclosure::Circled.char(42), Some('㊷'));
+    /// assert_eq!(Enclosure::Parenthesized.char(0), None);
+    /// ```
+    pub const fn char(self, n: u128) -> Option<char> {
+        let code = match (self, n) {
+            (Enclosure::Circled, 0) => 0x24ea,
+            (Enclosure::Circled, 1..=20) => 0x2460 + n as u32 - 1,
+            (Enclosure::Circled, 21..=35) => 0x3251 + n as u32 - 21,
+            (Enclosure::Circled, 36..=50) => 0x32b1 + n as u32 - 36,
+            (Enclosure::Parenthesized, 1..=20) => 0x2474 + n as u32 - 1,
+            (Enclosure::FullStop, 0) => 0x1f100,
+            (Enclosure::FullStop, 1..=20) => 0x2488 + n as u32 - 1,
+            _ => return None,
+        };
+        char::from_u32(code)
+    }
+
+    fn write_plain(
+        self,
+        f: &mut core::fmt::Formatter<'_>,
+        n: u128,
+        negative: bool,
+    ) -> core::fmt::Result {
+        let minus = if negative { "-" } else { "" };
+        match self {
+            Enclosure::Circled => write!(f, "{}{}", minus, n),
+            Enclosure::Parenthesized => write!(f, "({}{})", minus, n),
+            Enclosure::FullStop => write!(f, "{}{}.", minus, n),
+        }
+    }
+}
+
+/// Displays a number as a single enclosed character.
+///
+/// Numbers outside of the range of the [Enclosure] are written according to the [Fallback]. By
+/// default they are kept in plain digits with the parentheses or full stop of the enclosure, such
+/// that `21.to_parenthesized()` is written as `(21)`. [Fallback::Replace] writes the replacement
+/// instead of the whole number.
+///
+/// ```
+/// # use indexing_fmt::styled::*;
+/// use indexing_fmt::Fallback;
+///
+/// assert_eq!(format!("{}", 51.to_circled()), "51");
+/// assert_eq!(format!("{}", 21.to_full_stop()), "21.");
+/// assert_eq!(format!("{}", 21.to_full_stop().fallback(Fallback::Replace('•'))), "•");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Enclosed {
+    negative: bool,
+    value: u128,
+    enclosure: Enclosure,
+    fallback: Fallback,
+}
+
+impl Enclosed {
+    pub fn new(value: u128, enclosure: Enclosure) -> Self {
+        Enclosed {
+            negative: false,
+            value,
+            enclosure,
+            fallback: Fallback::Keep,
+        }
+    }
+
+    fn negative(self, negative: bool) -> Self {
+        Enclosed { negative, ..self }
+    }
+
+    /// Determines how numbers without enclosed form are written.
+    pub fn fallback(self, fallback: Fallback) -> Self {
+        Enclosed { fallback, ..self }
+    }
+
+    /// The enclosed character, if the number is in the range of the enclosure.
+    pub fn to_char(self) -> Option<char> {
+        match self.negative {
+            true => None,
+            false => self.enclosure.char(self.value),
+        }
+    }
+}
+
+impl Display for Enclosed {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match (self.to_char(), self.fallback) {
+            (Some(c), _) if !crate::plain_mode() => f.write_char(c),
+            (_, Fallback::Keep) | (Some(_), _) => {
+                self.enclosure.write_plain(f, self.value, self.negative)
+            }
+            (None, Fallback::Skip) => Ok(()),
+            (None, Fallback::Replace(c)) => f.write_char(c),
+            (None, Fallback::Error) => Err(core::fmt::Error),
+        }
+    }
+}
+
+/// Responsible for converting integers to enclosed characters.
+///
+/// See [Enclosed] for the handling of numbers which are out of range, including all negative ones.
+pub trait FormatEnclosed {
+    /// `①②③`
+    fn to_circled(&self) -> Enclosed;
+
+    /// `⑴⑵⑶`
+    fn to_parenthesized(&self) -> Enclosed;
+
+    /// `⒈⒉⒊`
+    fn to_full_stop(&self) -> Enclosed;
+}
+
+macro_rules! impl_enclosed(
+    ($($ty_unsigned:ty, $ty_signed:ty);* $(;)?) => {
+        $(
+            impl FormatEnclosed for $ty_unsigned {
+                fn to_circled(&self) -> Enclosed {
+                    Enclosed::new(*self as u128, Enclosure::Circled)
+                }
+
+                fn to_parenthesized(&self) -> Enclosed {
+                    Enclosed::new(*self as u128, Enclosure::Parenthesized)
+                }
+
+                fn to_full_stop(&self) -> Enclosed {
+                    Enclosed::new(*self as u128, Enclosure::FullStop)
+                }
+            }
+
+            impl FormatEnclosed for $ty_signed {
+                fn to_circled(&self) -> Enclosed {
+                    self.unsigned_abs().to_circled().negative(*self < 0)
+                }
+
+                fn to_parenthesized(&self) -> Enclosed {
+                    self.unsigned_abs().to_parenthesized().negative(*self < 0)
+                }
+
+                fn to_full_stop(&self) -> Enclosed {
+                    self.unsigned_abs().to_full_stop().negative(*self < 0)
+                }
+            }
+        )*
+    };
+);
+
+impl_enclosed!(u128, i128; usize, isize; u64, i64; u32, i32; u16, i16; u8, i8);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    extern crate std;
+
+    #[test]
+    fn all_styles() {
+        let styles = [
+            Style::Bold,
+            Style::DoubleStruck,
+            Style::SansSerif,
+            Style::SansSerifBold,
+            Style::Monospace,
+            Style::Fullwidth,
+        ];
+        let res = styles.map(|style| std::format!("{}", "0189".to_styled_digits(style)));
+        assert_eq!(res, ["𝟎𝟏𝟖𝟗", "𝟘𝟙𝟠𝟡", "𝟢𝟣𝟪𝟫", "𝟬𝟭𝟴𝟵", "𝟶𝟷𝟾𝟿", "０１８９"]);
+        assert_eq!(
+            std::format!("{}", StyledDigits::new("a-1", Style::Bold)),
+            "a-𝟏"
+        );
+    }
+
+    #[test]
+    fn enclosed_ranges() {
+        let circled: std::string::String = (0..=50u8)
+            .map(|n| n.to_circled().to_char().unwrap())
+            .collect();
+        assert_eq!(circled.chars().count(), 51);
+        assert!(circled.starts_with("⓪①") && circled.contains("⑳㉑") && circled.contains("㉟㊱"));
+        assert!(circled.ends_with('㊿'));
+        assert_eq!(20u8.to_parenthesized().to_char(), Some('⒇'));
+        assert_eq!(20u8.to_full_stop().to_char(), Some('⒛'));
+        assert_eq!(0u8.to_full_stop().to_char(), Some('🄀'));
+        let res = std::format!("{}", 0u8.to_parenthesized().fallback(Fallback::Skip));
+        assert_eq!(res, "");
+        assert_eq!(
+            std::format!("{}", u128::MAX.to_circled()),
+            std::format!("{}", u128::MAX)
+        );
+    }
+}
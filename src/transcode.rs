@@ -0,0 +1,95 @@
+//! Restyling of existing superscripts as subscripts and vice versa.
+//!
+//! ```
+//! use indexing_fmt::transcode::*;
+//!
+//! assert_eq!(format!("{}", to_subscripts("x⁽ⁿ⁺¹⁾")), "x₍ₙ₊₁₎");
+//! assert_eq!(format!("{}", to_superscripts("a₁₂ + bᵢ")), "a¹² + bⁱ");
+//! assert_eq!(format!("{}", to_subscripts("kᵝ")), "kᵦ");
+//! ```
+
+use core::fmt::{Display, Write};
+
+use crate::ScriptPosition;
+use crate::chars::{Fallback, UnicodeVersion, baseline_char, script_char};
+
+/// Displays text with every script of the opposite position converted.
+///
+/// Characters which are not scripts, or which already have the target position, are written
+/// unchanged. Scripts whose counterpart does not exist, such as `ᵟ` which has no subscript form,
+/// are written according to the [Fallback], which keeps them by default.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Transcoded<'a> {
+    text: &'a str,
+    target: ScriptPosition,
+    fallback: Fallback,
+    unicode_version: UnicodeVersion,
+}
+
+/// Converts the superscripts of the text into subscripts.
+pub fn to_subscripts(text: &str) -> Transcoded<'_> {
+    Transcoded::new(text, ScriptPosition::Subscript)
+}
+
+/// Converts the subscripts of the text into superscripts.
+pub fn to_superscripts(text: &str) -> Transcoded<'_> {
+    Transcoded::new(text, ScriptPosition::Superscript)
+}
+
+impl<'a> Transcoded<'a> {
+    fn new(text: &'a str, target: ScriptPosition) -> Self {
+        Transcoded {
+            text,
+            target,
+            fallback: Fallback::Keep,
+            unicode_version: UnicodeVersion::V6_0,
+        }
+    }
+
+    /// Determines how scripts without counterpart are written.
+    pub fn fallback(self, fallback: Fallback) -> Self {
+        Transcoded { fallback, ..self }
+    }
+
+    /// Restricts or extends the available script characters, see [UnicodeVersion].
+    pub fn unicode_version(self, unicode_version: UnicodeVersion) -> Self {
+        Transcoded {
+            unicode_version,
+            ..self
+        }
+    }
+}
+
+impl Display for Transcoded<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for c in self.text.chars() {
+            let base = match baseline_char(c) {
+                Some((base, position)) if position != self.target => base,
+                _ => {
+                    f.write_char(c)?;
+                    continue;
+                }
+            };
+            match script_char(base, self.target, self.unicode_version) {
+                Some(s) => f.write_char(s)?,
+                None => self.fallback.write(f, c)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    extern crate std;
+
+    #[test]
+    fn missing_counterparts() {
+        assert_eq!(std::format!("{}", to_subscripts("Tᵇ₁")), "Tᵇ₁");
+        let res = to_subscripts("Tᵇ").fallback(Fallback::Replace('?'));
+        assert_eq!(std::format!("{}", res), "T?");
+        let res = to_superscripts("ₓ⁻").fallback(Fallback::Error);
+        assert_eq!(std::format!("{}", res), "ˣ⁻");
+    }
+}
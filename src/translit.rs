@@ -0,0 +1,296 @@
+//! Transliteration of the digits and signs in arbitrary text.
+//!
+//! Only `0-9`, `+` and `-` are converted, all other characters are kept.
+//!
+//! ```
+//! use core::fmt::Write;
+//! use indexing_fmt::translit::*;
+//!
+//! let (a, b) = (3, 14);
+//! assert_eq!(format!("x{}", SuperscriptDigits(format_args!("{}-{}", a, b))), "x³⁻¹⁴");
+//! assert_eq!(format!("{}", SubscriptDigits("H2O")), "H₂O");
+//!
+//! let mut out = SuperscriptWriter::new(String::new());
+//! write!(out, "10{}", -3)?;
+//! assert_eq!(out.into_inner(), "¹⁰⁻³");
+//! # Ok::<(), core::fmt::Error>(())
+//! ```
+
+use core::fmt::{Display, Write};
+
+use crate::chars::{Fallback, Mapped, subscript_char, superscript_char};
+
+fn superscript_digit_char(c: char) -> Option<char> {
+    match c {
+        '0'..='9' | '+' | '-' => superscript_char(c),
+        _ => None,
+    }
+}
+
+fn subscript_digit_char(c: char) -> Option<char> {
+    match c {
+        '0'..='9' | '+' | '-' => subscript_char(c),
+        _ => None,
+    }
+}
+
+/// Displays the inner value with its digits and signs as superscripts.
+///
+/// The value is formatted lazily without intermediate buffer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SuperscriptDigits<D>(pub D);
+
+/// Displays the inner value with its digits and signs as subscripts.
+///
+/// The value is formatted lazily without intermediate buffer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SubscriptDigits<D>(pub D);
+
+impl<D: Display> Display for SuperscriptDigits<D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(SuperscriptWriter::new(f), "{}", self.0)
+    }
+}
+
+impl<D: Display> Display for SubscriptDigits<D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(SubscriptWriter::new(f), "{}", self.0)
+    }
+}
+
+macro_rules! impl_writer(
+    ($(#[$doc:meta])* $ty:ident, $map:ident) => {
+        $(#[$doc])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+        pub struct $ty<W> {
+            inner: W,
+        }
+
+        impl<W: Write> $ty<W> {
+            pub fn new(inner: W) -> Self {
+                $ty { inner }
+            }
+
+            pub fn get_ref(&self) -> &W {
+                &self.inner
+            }
+
+            pub fn get_mut(&mut self) -> &mut W {
+                &mut self.inner
+            }
+
+            pub fn into_inner(self) -> W {
+                self.inner
+            }
+        }
+
+        impl<W: Write> Write for $ty<W> {
+            fn write_str(&mut self, s: &str) -> core::fmt::Result {
+                s.chars().try_for_each(|c| self.write_char(c))
+            }
+
+            fn write_char(&mut self, c: char) -> core::fmt::Result {
+                Mapped {
+                    inner: &mut self.inner,
+                    map: $map,
+                    fallback: Fallback::Keep,
+                }
+                .write_char(c)
+            }
+        }
+    };
+);
+
+impl_writer!(
+    /// Writes to the inner writer with all digits and signs converted to superscripts.
+    SuperscriptWriter,
+    superscript_digit_char
+);
+impl_writer!(
+    /// Writes to the inner writer with all digits and signs converted to subscripts.
+    SubscriptWriter,
+    subscript_digit_char
+);
+
+/// A set of characters which replace the decimal digits and signs.
+///
+/// Implementing this trait allows to use custom character sets with [ScriptedWith], for example
+/// the private use characters of an embedded font.
+///
+/// ```
+/// # use indexing_fmt::translit::*;
+/// struct Boxed;
+///
+/// impl DigitTable for Boxed {
+///     const DIGITS: [char; 10] = ['🄌', '➊', '➋', '➌', '➍', '➎', '➏', '➐', '➑', '➒'];
+///     const PLUS: char = '+';
+///     const MINUS: char = '-';
+/// }
+///
+/// assert_eq!(format!("{}", ScriptedWith::<_, Boxed>::new(-42)), "-➍➋");
+/// ```
+pub trait DigitTable {
+    /// The characters of the digits, indexed by their value.
+    const DIGITS: [char; 10];
+    /// The plus sign.
+    const PLUS: char;
+    /// The minus sign.
+    const MINUS: char;
+}
+
+/// The superscript digits and signs `⁰¹²³⁴⁵⁶⁷⁸⁹⁺⁻`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SuperscriptTable;
+
+impl DigitTable for SuperscriptTable {
+    const DIGITS: [char; 10] = crate::SUPERSCRIPT_DIGITS;
+    const PLUS: char = '\u{207a}';
+    const MINUS: char = '\u{207b}';
+}
+
+/// The subscript digits and signs `₀₁₂₃₄₅₆₇₈₉₊₋`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SubscriptTable;
+
+impl DigitTable for SubscriptTable {
+    const DIGITS: [char; 10] = crate::SUBSCRIPT_DIGITS;
+    const PLUS: char = '\u{208a}';
+    const MINUS: char = '\u{208b}';
+}
+
+fn table_char<T: DigitTable>(c: char) -> Option<char> {
+    match c {
+        '0'..='9' => Some(T::DIGITS[c as usize - '0' as usize]),
+        '+' => Some(T::PLUS),
+        '-' => Some(T::MINUS),
+        _ => None,
+    }
+}
+
+/// Displays the inner value with its digits and signs replaced by the characters of a
+/// [DigitTable].
+///
+/// All other characters are kept, like for [SuperscriptDigits].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ScriptedWith<D, T> {
+    value: D,
+    table: core::marker::PhantomData<T>,
+}
+
+impl<D, T: DigitTable> ScriptedWith<D, T> {
+    pub fn new(value: D) -> Self {
+        ScriptedWith {
+            value,
+            table: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<D: Display, T: DigitTable> Display for ScriptedWith<D, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut mapped = Mapped {
+            inner: f,
+            map: table_char::<T>,
+            fallback: Fallback::Keep,
+        };
+        write!(mapped, "{}", self.value)
+    }
+}
+
+/// A character without script form.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Unmappable(pub char);
+
+impl Display for Unmappable {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:?} has no script form", self.0)
+    }
+}
+
+impl core::error::Error for Unmappable {}
+
+/// Transliteration of existing text into new strings.
+///
+/// This trait requires the `alloc` feature.
+///
+/// ```
+/// # use indexing_fmt::translit::*;
+/// assert_eq!("H2O and CO2".subscript_digits(), "H₂O and CO₂");
+/// assert_eq!("n+1".to_superscript_strict(), Ok("ⁿ⁺¹".into()));
+/// assert_eq!("q2".to_subscript_strict(), Err(Unmappable('q')));
+/// ```
+#[cfg(feature = "alloc")]
+pub trait TranslitStr {
+    /// Converts the digits and signs to superscripts and keeps all other characters.
+    fn superscript_digits(&self) -> alloc::string::String;
+
+    /// Converts the digits and signs to subscripts and keeps all other characters.
+    fn subscript_digits(&self) -> alloc::string::String;
+
+    /// Converts every character to its superscript form, failing at the first one without.
+    fn to_superscript_strict(&self) -> Result<alloc::string::String, Unmappable>;
+
+    /// Converts every character to its subscript form, failing at the first one without.
+    fn to_subscript_strict(&self) -> Result<alloc::string::String, Unmappable>;
+}
+
+#[cfg(feature = "alloc")]
+impl TranslitStr for str {
+    fn superscript_digits(&self) -> alloc::string::String {
+        alloc::format!("{}", SuperscriptDigits(self))
+    }
+
+    fn subscript_digits(&self) -> alloc::string::String {
+        alloc::format!("{}", SubscriptDigits(self))
+    }
+
+    fn to_superscript_strict(&self) -> Result<alloc::string::String, Unmappable> {
+        self.chars()
+            .map(|c| superscript_char(c).ok_or(Unmappable(c)))
+            .collect()
+    }
+
+    fn to_subscript_strict(&self) -> Result<alloc::string::String, Unmappable> {
+        self.chars()
+            .map(|c| subscript_char(c).ok_or(Unmappable(c)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    extern crate std;
+
+    #[test]
+    fn only_digits_and_signs() {
+        let res = std::format!("{}", SuperscriptDigits("a+1 = (b-2)"));
+        assert_eq!(res, "a⁺¹ = (b⁻²)");
+        assert_eq!(std::format!("{}", SubscriptDigits(-1.25)), "₋₁.₂₅");
+    }
+
+    #[test]
+    fn digit_tables() {
+        let res = std::format!("{}", ScriptedWith::<_, SuperscriptTable>::new("x+10"));
+        assert_eq!(res, "x⁺¹⁰");
+        let res = std::format!("{}", ScriptedWith::<_, SubscriptTable>::new(-2.5));
+        assert_eq!(res, std::format!("{}", SubscriptDigits(-2.5)));
+    }
+
+    #[test]
+    fn writers() {
+        let mut out = SubscriptWriter::new(std::string::String::new());
+        out.write_str("CO").unwrap();
+        out.write_char('2').unwrap();
+        assert_eq!(out.get_ref(), "CO₂");
+        out.get_mut().push('3');
+        assert_eq!(out.into_inner(), "CO₂3");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn strict_ignores_nothing() {
+        assert_eq!("x 2".to_superscript_strict(), Err(Unmappable(' ')));
+        assert_eq!("".to_subscript_strict(), Ok("".into()));
+    }
+}
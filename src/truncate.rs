@@ -0,0 +1,137 @@
+//! Shortening of labels with scripts to a fixed number of display columns.
+//!
+//! ```
+//! use indexing_fmt::*;
+//! use indexing_fmt::truncate::Truncated;
+//!
+//! let label = format!("Docking-Bay{} Alpha", 840.to_subscript());
+//! assert_eq!(format!("{}", Truncated::new(&label, 16)), "Docking-Bay₈₄₀ …");
+//! // The index is never separated from its base
+//! assert_eq!(format!("{}", Truncated::new(&label, 13)), "Docking-Ba…");
+//! ```
+
+use core::fmt::{Display, Write};
+
+use crate::chars::baseline_char;
+
+/// The number of terminal columns a character occupies.
+///
+/// Combining diacritical marks occupy no column, all other characters one.
+fn columns(c: char) -> usize {
+    match c {
+        '\u{0300}'..='\u{036f}' => 0,
+        _ => 1,
+    }
+}
+
+fn is_script(c: char) -> bool {
+    baseline_char(c).is_some()
+}
+
+/// Finds the number of characters to keep.
+///
+/// Cuts are only possible in front of characters which are neither scripts nor combining marks.
+struct Measure {
+    max_columns: usize,
+    columns: usize,
+    chars: usize,
+    cut: usize,
+}
+
+impl Write for Measure {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        for c in s.chars() {
+            if !is_script(c) && columns(c) > 0 && self.columns <= self.max_columns {
+                self.cut = self.chars;
+            }
+            self.columns += columns(c);
+            self.chars += 1;
+        }
+        Ok(())
+    }
+}
+
+/// Passes on the first `remaining` characters.
+struct Prefix<'a, W> {
+    inner: &'a mut W,
+    remaining: usize,
+}
+
+impl<W: Write> Write for Prefix<'_, W> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        for c in s.chars().take(self.remaining) {
+            self.inner.write_char(c)?;
+            self.remaining -= 1;
+        }
+        Ok(())
+    }
+}
+
+/// Displays a value shortened to at most the given number of columns, ending in an ellipsis.
+///
+/// A script run is either kept completely together with the character before it or removed
+/// completely, such that no label appears to carry a different index.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Truncated<D> {
+    value: D,
+    max_columns: usize,
+    ellipsis: char,
+}
+
+impl<D: Display> Truncated<D> {
+    pub fn new(value: D, max_columns: usize) -> Self {
+        Truncated {
+            value,
+            max_columns,
+            ellipsis: '\u{2026}',
+        }
+    }
+
+    /// Replaces the ellipsis `…`.
+    pub fn ellipsis(self, ellipsis: char) -> Self {
+        Truncated { ellipsis, ..self }
+    }
+}
+
+impl<D: Display> Display for Truncated<D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut measure = Measure {
+            // One column is reserved for the ellipsis
+            max_columns: self.max_columns.saturating_sub(1),
+            columns: 0,
+            chars: 0,
+            cut: 0,
+        };
+        write!(measure, "{}", self.value)?;
+        if measure.columns <= self.max_columns {
+            return write!(f, "{}", self.value);
+        }
+        if self.max_columns == 0 {
+            return Ok(());
+        }
+        let mut prefix = Prefix {
+            inner: f,
+            remaining: measure.cut,
+        };
+        write!(prefix, "{}", self.value)?;
+        f.write_char(self.ellipsis)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    extern crate std;
+
+    #[test]
+    fn keeps_scripts_with_base() {
+        let res = std::format!("{}", Truncated::new("x₁₂₃y", 4));
+        assert_eq!(res, "…");
+        let res = std::format!("{}", Truncated::new("ab x₁₂₃", 6));
+        assert_eq!(res, "ab …");
+        assert_eq!(std::format!("{}", Truncated::new("abc", 3)), "abc");
+        assert_eq!(std::format!("{}", Truncated::new("abcd", 0)), "");
+        let res = Truncated::new("p̄⁻ and more", 4).ellipsis('~');
+        assert_eq!(std::format!("{}", res), "p̄⁻ ~");
+    }
+}
@@ -0,0 +1,75 @@
+//! Support for the nonstandard integer widths of the [ux] crate.
+//!
+//! Values are widened to the next standard integer type before formatting.
+
+use core::fmt::Display;
+
+use crate::{FormatSubscript, FormatSuperscript, Subscript, Superscript};
+
+macro_rules! impl_ux(
+    ($($ty:ident => $wide:ty),* $(,)?) => {
+        $(
+            impl Display for Superscript<ux::$ty> {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    Superscript(<$wide>::from(self.0)).fmt(f)
+                }
+            }
+
+            impl Display for Subscript<ux::$ty> {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    Subscript(<$wide>::from(self.0)).fmt(f)
+                }
+            }
+
+            impl FormatSuperscript for ux::$ty {
+                fn to_superscript(&self) -> Superscript<ux::$ty> {
+                    Superscript(*self)
+                }
+            }
+
+            impl FormatSubscript for ux::$ty {
+                fn to_subscript(&self) -> Subscript<ux::$ty> {
+                    Subscript(*self)
+                }
+            }
+        )*
+    };
+);
+
+impl_ux!(
+    u1 => u8, u2 => u8, u3 => u8, u4 => u8, u5 => u8, u6 => u8, u7 => u8, u9 => u16, u10 => u16,
+    u11 => u16, u12 => u16, u13 => u16, u14 => u16, u15 => u16, u17 => u32, u18 => u32, u19 =>
+    u32, u20 => u32, u21 => u32, u22 => u32, u23 => u32, u24 => u32, u25 => u32, u26 => u32, u27
+    => u32, u28 => u32, u29 => u32, u30 => u32, u31 => u32, u33 => u64, u34 => u64, u35 => u64,
+    u36 => u64, u37 => u64, u38 => u64, u39 => u64, u40 => u64, u41 => u64, u42 => u64, u43 =>
+    u64, u44 => u64, u45 => u64, u46 => u64, u47 => u64, u48 => u64, u49 => u64, u50 => u64, u51
+    => u64, u52 => u64, u53 => u64, u54 => u64, u55 => u64, u56 => u64, u57 => u64, u58 => u64,
+    u59 => u64, u60 => u64, u61 => u64, u62 => u64, u63 => u64,
+);
+
+impl_ux!(
+    i2 => i8, i3 => i8, i4 => i8, i5 => i8, i6 => i8, i7 => i8, i9 => i16, i10 => i16,
+    i11 => i16, i12 => i16, i13 => i16, i14 => i16, i15 => i16, i17 => i32, i18 => i32, i19 =>
+    i32, i20 => i32, i21 => i32, i22 => i32, i23 => i32, i24 => i32, i25 => i32, i26 => i32, i27
+    => i32, i28 => i32, i29 => i32, i30 => i32, i31 => i32, i33 => i64, i34 => i64, i35 => i64,
+    i36 => i64, i37 => i64, i38 => i64, i39 => i64, i40 => i64, i41 => i64, i42 => i64, i43 =>
+    i64, i44 => i64, i45 => i64, i46 => i64, i47 => i64, i48 => i64, i49 => i64, i50 => i64, i51
+    => i64, i52 => i64, i53 => i64, i54 => i64, i55 => i64, i56 => i64, i57 => i64, i58 => i64,
+    i59 => i64, i60 => i64, i61 => i64, i62 => i64, i63 => i64,
+);
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    extern crate std;
+
+    #[test]
+    fn nonstandard_widths() {
+        let res = std::format!("{}", ux::u7::MAX.to_superscript());
+        assert_eq!(res, "¹²⁷");
+        let res = std::format!("{}", ux::i48::MIN.to_subscript());
+        assert_eq!(res, "₋₁₄₀₇₃₇₄₈₈₃₅₅₃₂₈");
+        let res = std::format!("{}", ux::u24::new(0).to_subscript());
+        assert_eq!(res, "₀");
+    }
+}
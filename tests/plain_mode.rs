@@ -0,0 +1,51 @@
+//! Plain mode is a global switch, so these tests run in their own process and never disable it.
+
+use indexing_fmt::{chem, math, physics, sci, set_plain_mode};
+
+#[test]
+fn chem_plain() {
+    set_plain_mode(true);
+    assert_eq!(format!("{}", chem::formula("H2O")), "H2O");
+    assert_eq!(format!("{}", chem::ion("sulfate").unwrap()), "SO42-");
+    assert_eq!(format!("Na{}", chem::Charge(1)), "Na+");
+    assert_eq!(
+        format!("{}", chem::equation("Ca^2+ + 2 Cl-")),
+        "Ca2+ + 2 Cl-"
+    );
+}
+
+#[test]
+fn physics_plain() {
+    set_plain_mode(true);
+    let res = physics::Particle::new("K").charge(0).label("S");
+    assert_eq!(format!("{}", res), "K0_S");
+    assert_eq!(format!("{}", physics::Particle::new("Δ").charge(2)), "Δ++");
+    assert_eq!(format!("{}", physics::Particle::new("ν").label("e")), "νe");
+}
+
+#[test]
+fn math_plain() {
+    set_plain_mode(true);
+    assert_eq!(format!("{}", math::Derivative::new("f", 4)), "f(4)");
+    assert_eq!(format!("{}", math::Derivative::new("f", "n")), "f(n)");
+    assert_eq!(format!("{}", math::primes(5).parenthesized_above(3)), "(5)");
+}
+
+#[test]
+fn sci_plain() {
+    set_plain_mode(true);
+    assert_eq!(format!("{}", sci::sci(1234.5)), "1.2345×10^3");
+    assert_eq!(format!("{}", sci::si(2.5e40, "J")), "2.5×10^40 J");
+    assert_eq!(format!("{}", sci::sci(0.042).engineering()), "42×10^-3");
+}
+
+#[cfg(feature = "cldr")]
+#[test]
+fn cldr_plain() {
+    use indexing_fmt::cldr::{Locale, ordinal};
+    set_plain_mode(true);
+    assert_eq!(format!("{}", ordinal(22, Locale::English)), "22nd");
+    assert_eq!(format!("{}", ordinal(1, Locale::French)), "1er");
+    assert_eq!(format!("{}", ordinal(3, Locale::Spanish)), "3o");
+    assert_eq!(format!("{}", ordinal(21, Locale::Swedish)), "21:a");
+}